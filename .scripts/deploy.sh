@@ -10,5 +10,5 @@ mkdir -p "$dest"
 
 cp -r index.html sounds img pkg "$dest"
 
-## Remove the ignore file on the pkg directory
-rm $dest/pkg/.gitignore
+## Remove the ignore file on the pkg directory, if any
+rm -f $dest/pkg/.gitignore
@@ -2,9 +2,13 @@
 
 set -v
 
-if ! type wasm-pack > /dev/null; then
-    echo "wasm-pack is not installed"
-    cargo install wasm-pack
+rustup target add wasm32-unknown-unknown
+
+# the wasm-bindgen cli must be the same version as the wasm-bindgen crate of the build
+wasm_bindgen_version=$(cargo pkgid wasm-bindgen | sed 's/.*[#@]//')
+if [ "$(wasm-bindgen --version 2> /dev/null)" != "wasm-bindgen $wasm_bindgen_version" ]; then
+    echo "wasm-bindgen $wasm_bindgen_version is not installed"
+    cargo install wasm-bindgen-cli --version "$wasm_bindgen_version"
 fi
 
 if ! type basic-http-server > /dev/null; then
@@ -1,4 +1,6 @@
 #!/bin/bash
 set -ev
 
-wasm-pack build --target web --release -- 
+cargo build --target wasm32-unknown-unknown --release --example demo
+wasm-bindgen --target web --out-dir pkg --out-name futuristic_ui \
+    target/wasm32-unknown-unknown/release/examples/demo.wasm
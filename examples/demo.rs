@@ -0,0 +1,816 @@
+//! The showcase application of the widgets, mounted at `#app_container` in index.html
+//! and built with `build.sh`.
+use futuristic_ui::{
+    animate_list,
+    animate_list::AnimateList,
+    button,
+    button::{Button, Options},
+    common, frame,
+    frame::Frame,
+    icon,
+    icon::Icon,
+    image,
+    image::Image,
+    nav_header,
+    nav_header::NavHeader,
+    paragraph,
+    paragraph::Paragraph,
+    sounds::SoundManager,
+    spinner::Spinner,
+    ssr, text_input,
+    text_input::{TextInput, Validation},
+    theme::{ColorScheme, Theme, ThemeError, ThemeRegistry},
+    Context,
+};
+use sauron::jss;
+use sauron::{
+    html::units::em,
+    html::{attributes::class, div, text},
+    prelude::*,
+    Application, Cmd, Node, Program,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// class of the style element containing the stylesheets of the widgets
+const STYLE_CLASS: &str = "futuristic-ui";
+/// class of the style element containing the css variables of the theme
+const VARIABLES_STYLE_CLASS: &str = "futuristic-ui-variables";
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    HashChanged(String),
+    /// load a theme from a json theme file content
+    LoadTheme(String),
+    /// switch to a named theme in the theme registry
+    SetTheme(String),
+    /// use the light or dark theme based on the user's system preference
+    FollowSystemScheme,
+    ReAnimateFrame,
+    ReAnimateHeader,
+    ReAnimateParagraph,
+    ReAnimateList,
+    ButtonMsg(Rc<RefCell<button::Button<Msg>>>, button::Msg),
+    TextInputMsg(Rc<RefCell<TextInput<Msg>>>, text_input::Msg),
+    FrameMsg(Box<frame::Msg<Msg>>),
+    NavHeaderMsg(nav_header::Msg),
+    ParagraphMsg(paragraph::Msg),
+    AnimateListMsg(animate_list::Msg),
+    ImageEffectsMsg(image::Msg),
+    SetMeasurements(Measurements),
+    StartAnimateImageEffects,
+    ReAnimateAll,
+    /// mute or unmute all the sounds
+    ToggleMute,
+    NoOp,
+}
+
+pub struct App {
+    nav_header: NavHeader<Msg>,
+    frame: Frame<Msg>,
+    paragraph: Paragraph<Msg>,
+    spinner: Spinner<Msg>,
+    animate_list: AnimateList<Msg>,
+    image: Image<Msg>,
+    theme: Theme,
+    themes: ThemeRegistry,
+    /// the reason why the theme in the url could not be used
+    theme_error: Option<String>,
+    btn_context: RefCell<Context<Button<Msg>, Msg, button::Msg>>,
+    input_context: RefCell<Context<TextInput<Msg>, Msg, text_input::Msg>>,
+    measurements: Option<Measurements>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        let themes = ThemeRegistry::new();
+        let frame_content = div(
+            vec![styles([("padding", "20px 40px"), ("font-size", "32px")])],
+            vec![text("Retro Futuristic UI in rust")],
+        )
+        .add_children(vec![
+            Self::show_color_selection(),
+            Self::show_theme_presets(&themes),
+        ]);
+
+        App {
+            frame: Frame::with_content(frame_content),
+            nav_header: NavHeader::with_content("Navigation Header"),
+            paragraph: Paragraph::new_with_markdown(
+                "A simple paragrah example",
+            ),
+            spinner: Spinner::new(),
+            animate_list: AnimateList::with_content(
+                Self::animate_list_content(),
+            ),
+            image: Image::new("img/space.jpg"),
+            theme: themes.current().clone(),
+            themes,
+            theme_error: None,
+            btn_context: RefCell::new(Context::new()),
+            input_context: RefCell::new(Context::new()),
+            measurements: None,
+        }
+    }
+}
+
+impl Application<Msg> for App {
+    fn init(&mut self) -> Cmd<Self, Msg> {
+        let hash = sauron::window().location().hash().expect("must get hash");
+        self.restyle(&hash);
+        let cmd_hash_changed = Window::on_hashchange(Msg::HashChanged);
+        Self::reanimate_all().append(vec![cmd_hash_changed])
+    }
+
+    fn update(&mut self, msg: Msg) -> Cmd<Self, Msg> {
+        match msg {
+            Msg::HashChanged(hash) => {
+                self.restyle(&hash);
+                Self::reanimate_all().measure()
+            }
+            Msg::LoadTheme(json) => match Theme::from_json(&json) {
+                Ok(theme) => {
+                    self.apply_theme(theme);
+                    Self::reanimate_all().measure()
+                }
+                Err(e) => {
+                    log::error!("unable to load theme: {}", e);
+                    Cmd::none()
+                }
+            },
+            // derive the light and dark themes from the primary color in use
+            Msg::FollowSystemScheme => {
+                match self.theme.primary_color.parse::<ColorScheme>() {
+                    Ok(scheme) => {
+                        self.apply_color_scheme(scheme);
+                        Self::reanimate_all().measure()
+                    }
+                    Err(e) => {
+                        log::error!("unable to derive the color scheme: {}", e);
+                        Cmd::none()
+                    }
+                }
+            }
+            Msg::SetTheme(theme_name) => {
+                if let Some(theme) = self.themes.set_theme(&theme_name) {
+                    let theme = theme.clone();
+                    self.apply_theme(theme);
+                    Self::reanimate_all().measure()
+                } else {
+                    log::warn!("no theme registered with name: {}", theme_name);
+                    Cmd::none()
+                }
+            }
+            Msg::ReAnimateHeader => {
+                let effects =
+                    self.nav_header.update(nav_header::Msg::AnimateIn);
+                Cmd::from(effects.localize(Msg::NavHeaderMsg)).measure()
+            }
+            Msg::NavHeaderMsg(header_msg) => {
+                let effects = self.nav_header.update(header_msg);
+                Cmd::from(effects.localize(Msg::NavHeaderMsg)).measure()
+            }
+            Msg::ReAnimateFrame => {
+                let effects = self.frame.update(frame::Msg::AnimateIn);
+                Cmd::from(
+                    effects.localize(|fmsg| Msg::FrameMsg(Box::new(fmsg))),
+                )
+                .measure()
+            }
+            Msg::FrameMsg(frame_msg) => {
+                let effects = self.frame.update(*frame_msg);
+                Cmd::from(
+                    effects.localize(|fmsg| Msg::FrameMsg(Box::new(fmsg))),
+                )
+                .measure()
+            }
+            Msg::ButtonMsg(btn, btn_msg) => {
+                let effects = self.btn_context.borrow_mut().update_component(
+                    btn,
+                    btn_msg,
+                    Msg::ButtonMsg,
+                );
+                Cmd::from(effects)
+            }
+            Msg::TextInputMsg(input, input_msg) => {
+                let effects = self.input_context.borrow_mut().update_component(
+                    input,
+                    input_msg,
+                    Msg::TextInputMsg,
+                );
+                Cmd::from(effects)
+            }
+            Msg::AnimateListMsg(animate_list_msg) => {
+                let effects = self.animate_list.update(animate_list_msg);
+                Cmd::from(effects.localize(Msg::AnimateListMsg)).measure()
+            }
+            Msg::ReAnimateList => {
+                let effects =
+                    self.animate_list.update(animate_list::Msg::AnimateIn);
+                Cmd::from(effects.localize(Msg::AnimateListMsg)).measure()
+            }
+            Msg::ParagraphMsg(para_msg) => {
+                let effects = self.paragraph.update(para_msg);
+                Cmd::from(effects.localize(Msg::ParagraphMsg)).measure()
+            }
+            Msg::ImageEffectsMsg(effects_msg) => {
+                let effects = self.image.update(effects_msg);
+                Cmd::from(effects.localize(Msg::ImageEffectsMsg)).measure()
+            }
+            Msg::StartAnimateImageEffects => {
+                let effects = self.image.update(image::Msg::AnimateIn);
+                Cmd::from(effects.localize(Msg::ImageEffectsMsg)).measure()
+            }
+            Msg::ReAnimateParagraph => {
+                let effects = self.paragraph.update(paragraph::Msg::AnimateIn);
+                Cmd::from(effects.localize(Msg::ParagraphMsg)).measure()
+            }
+            Msg::SetMeasurements(measurements) => {
+                self.measurements = Some(measurements);
+                Cmd::none()
+            }
+            Msg::ReAnimateAll => Self::reanimate_all().measure(),
+            Msg::ToggleMute => {
+                SoundManager::with(|manager| manager.toggle_mute());
+                Cmd::none()
+            }
+            Msg::NoOp => Cmd::none(),
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        let mut btn_context = self.btn_context.borrow_mut();
+        let mut input_context = self.input_context.borrow_mut();
+        div(
+            vec![class("container")],
+            vec![
+                self.nav_header.view().map_msg(Msg::NavHeaderMsg),
+                div(
+                    vec![class("measurements")],
+                    vec![if let Some(measurements) = &self.measurements {
+                        text!("total patches: {}, node_count: {}, update took: {}ms", measurements.total_patches, measurements.view_node_count, measurements.total_time)
+                    } else {
+                        text("")
+                    }],
+                ),
+                view_if(
+                    self.theme_error.is_some(),
+                    div(
+                        [class("theme_error")],
+                        [text(self.theme_error.as_deref().unwrap_or_default())],
+                    ),
+                ),
+                div(
+                    vec![
+                        style! {"padding":px(20), "position": "relative", "left": format!("calc({} - {})", percent(50), px(400 / 2))},
+                    ],
+                    vec![btn_context.map_view(
+                        "button",
+                        {
+                            Button::<Msg>::with_label("Welcome")
+                                .width(400)
+                                .height(100)
+                                .add_click_listener(|_| Msg::ReAnimateAll)
+                                .with_options(Options::full())
+                        },
+                        Msg::ButtonMsg,
+                    )],
+                ),
+                self.frame
+                    .view()
+                    .map_msg(|fmsg| Msg::FrameMsg(Box::new(fmsg))),
+                div(
+                    vec![class("futuristic-buttons")],
+                    vec![
+                        btn_context.map_view(
+                            "reanimate",
+                            {
+                                Button::with_label("Re-Animate All")
+                                    .with_leading_icon(Icon::Play.view())
+                                    .add_click_listener(|_| Msg::ReAnimateAll)
+                            },
+                            Msg::ButtonMsg,
+                        ),
+                        btn_context.map_view(
+                            "animate_frame",
+                            {
+                                Button::with_label("Animate Frame")
+                                    .skewed()
+                                    .add_click_listener(|_| Msg::ReAnimateFrame)
+                            },
+                            Msg::ButtonMsg,
+                        ),
+                        btn_context.map_view(
+                            "reanimate_icon",
+                            {
+                                Button::icon_only(
+                                    "Re-Animate All",
+                                    Icon::Play.view(),
+                                )
+                                .add_click_listener(|_| Msg::ReAnimateAll)
+                            },
+                            Msg::ButtonMsg,
+                        ),
+                        {
+                            let muted = SoundManager::with(|m| m.is_muted());
+                            // keyed by the mute, so the icon is recreated when toggled
+                            let (key, label, icon) = if muted {
+                                ("unmute", "Unmute sounds", Icon::Mute)
+                            } else {
+                                ("mute", "Mute sounds", Icon::Sound)
+                            };
+                            btn_context.map_view(
+                                key,
+                                Button::icon_only(label, icon.view())
+                                    .add_click_listener(|_| Msg::ToggleMute),
+                                Msg::ButtonMsg,
+                            )
+                        },
+                        btn_context.map_view(
+                            "error",
+                            Button::with_label("Error").error(),
+                            Msg::ButtonMsg,
+                        ),
+                        btn_context.map_view(
+                            "success",
+                            Button::with_label("Success").success(),
+                            Msg::ButtonMsg,
+                        ),
+                        btn_context.map_view(
+                            "info",
+                            Button::with_label("Info").info(),
+                            Msg::ButtonMsg,
+                        ),
+                        btn_context.map_view(
+                            "warning",
+                            Button::with_label("Warning").warning(),
+                            Msg::ButtonMsg,
+                        ),
+                    ],
+                ),
+                input_context.map_view(
+                    "callsign",
+                    TextInput::with_placeholder(
+                        "Callsign, enter to re-animate",
+                    )
+                    .width(300)
+                    .with_validator(|callsign| {
+                        if callsign.is_empty() {
+                            None
+                        } else if callsign.chars().all(char::is_alphanumeric) {
+                            Some(Validation::Success)
+                        } else {
+                            Some(Validation::Error)
+                        }
+                    })
+                    .add_submit_listener(|_| Msg::ReAnimateAll),
+                    Msg::TextInputMsg,
+                ),
+                btn_context.map_view(
+                    "animate_image",
+                    {
+                        Button::<Msg>::with_label("Animate Image")
+                            .chipped()
+                            .add_click_listener(|_| {
+                                Msg::StartAnimateImageEffects
+                            })
+                    },
+                    Msg::ButtonMsg,
+                ),
+                btn_context.map_view(
+                    "chip_error",
+                    Button::with_label("Error").chipped().error(),
+                    Msg::ButtonMsg,
+                ),
+                btn_context.map_view(
+                    "chip_success",
+                    Button::with_label("Success").chipped().success(),
+                    Msg::ButtonMsg,
+                ),
+                btn_context.map_view(
+                    "chip_info",
+                    Button::with_label("Info").chipped().info(),
+                    Msg::ButtonMsg,
+                ),
+                btn_context.map_view(
+                    "chip_warning",
+                    Button::with_label("Warning").chipped().warning(),
+                    Msg::ButtonMsg,
+                ),
+                self.image.view().map_msg(Msg::ImageEffectsMsg),
+                btn_context.map_view(
+                    "animate_list",
+                    Button::<Msg>::with_label("Animate List")
+                        .add_click_listener(|_| Msg::ReAnimateList),
+                    Msg::ButtonMsg,
+                ),
+                p(vec![], vec![self.animate_list.view()]),
+                self.spinner.view(),
+                btn_context.map_view(
+                    "animate_paragraph",
+                    {
+                        Button::<Msg>::with_label("Animate Paragraph")
+                            .add_click_listener(|_| Msg::ReAnimateParagraph)
+                    },
+                    Msg::ButtonMsg,
+                ),
+                // click on the paragraph to skip the typing animation
+                div(
+                    [on_click(|_| {
+                        Msg::ParagraphMsg(paragraph::Msg::SkipToEnd)
+                    })],
+                    [self.paragraph.view()],
+                ),
+                footer(
+                    vec![],
+                    vec![a(
+                        vec![href(
+                            "https://github.com/ivanceras/futuristic-ui/",
+                        )],
+                        vec![text("code")],
+                    )],
+                ),
+            ],
+        )
+    }
+
+    fn measurements(&self, measurements: Measurements) -> Cmd<Self, Msg> {
+        log::info!("measurements: {:#?}", measurements);
+        Cmd::batch_msg(vec![Msg::SetMeasurements(measurements)])
+    }
+}
+
+impl App {
+    /// Returns None if there is no theme specified in the url hash,
+    /// the active theme of the registry is used instead.
+    fn calculate_theme_from_url_hash(
+        hash: &str,
+    ) -> Option<Result<Theme, ThemeError>> {
+        if hash
+            .trim_start_matches('#')
+            .trim_start_matches('/')
+            .is_empty()
+        {
+            None
+        } else {
+            Some(Theme::from_url_hash(hash))
+        }
+    }
+
+    fn restyle(&mut self, hash: &str) {
+        let (theme, theme_error) =
+            match Self::calculate_theme_from_url_hash(hash) {
+                Some(Ok(theme)) => (theme, None),
+                Some(Err(e)) => {
+                    log::error!("invalid theme in url {:?}: {}", hash, e);
+                    (self.theme.clone(), Some(e.to_string()))
+                }
+                None => (self.themes.current().clone(), None),
+            };
+        self.apply_theme(theme);
+        self.theme_error = theme_error;
+    }
+
+    /// replace the theme and regenerate all the styles
+    fn apply_theme(&mut self, theme: Theme) {
+        let css_variables = theme.css_variables();
+        self.apply_css_variables(theme, &css_variables);
+    }
+
+    /// follow the light or dark preference of the user's system
+    fn apply_color_scheme(&mut self, scheme: ColorScheme) {
+        let css_variables = scheme.css_variables();
+        self.apply_css_variables(scheme.dark, &css_variables);
+    }
+
+    fn apply_css_variables(&mut self, theme: Theme, css_variables: &str) {
+        self.theme = theme;
+        self.theme_error = None;
+        // the stylesheets only refers to the css variables,
+        // so they are generated only once and switching theme only
+        // replaces the css variables
+        if !Self::has_style(STYLE_CLASS) {
+            let variable_theme = self.theme.with_css_variables();
+            Self::inject_style(STYLE_CLASS, &self.stylesheet(&variable_theme));
+        }
+        Self::remove_style(VARIABLES_STYLE_CLASS);
+        Self::inject_style(VARIABLES_STYLE_CLASS, css_variables);
+    }
+
+    fn has_style(style_class: &str) -> bool {
+        sauron::document()
+            .query_selector(&format!(".{}", style_class))
+            .expect("must query")
+            .is_some()
+    }
+
+    fn remove_style(style_class: &str) {
+        use sauron::wasm_bindgen::JsCast;
+        let document = sauron::document();
+        if let Some(html_style) = document
+            .query_selector(&format!(".{}", style_class))
+            .expect("must query")
+        {
+            let html_style: web_sys::Element = html_style.unchecked_into();
+            html_style.remove();
+        }
+    }
+
+    /// all of the styles used in the app
+    fn stylesheet(&self, theme: &Theme) -> String {
+        let styles = self.style(theme);
+        let mut all_styles = common::style(theme);
+        all_styles += &format!("\n{}", styles.join("\n"));
+        all_styles
+    }
+
+    /// render the app with its styles into a static html page
+    pub fn render_page(&self) -> String {
+        ssr::render_page(
+            "Futuristic UI",
+            &self.stylesheet(&self.theme),
+            &self.view(),
+        )
+    }
+
+    /// We are using a custom way to put style
+    fn style(&self, theme: &Theme) -> Vec<String> {
+        let base = theme;
+        let controls_content_background_color =
+            base.controls.content_background_color.to_owned();
+        let controls_button_text_color =
+            base.controls.button_text_color.to_owned();
+        let secondary_color = base.secondary_color.to_owned();
+
+        let accent_shadow = base.accent_shadow.to_owned();
+        let accent_color = base.accent_color.to_owned();
+
+        let primary_font = base.primary_font.to_owned();
+        let secondary_font = base.secondary_font.to_owned();
+        let controls_border_color = base.controls.border_color.to_owned();
+        let background_color = base.background_color.to_owned();
+
+        let body_css = jss! {
+
+            button: {
+                color: controls_button_text_color.clone(),
+                border: format!("{} solid {}",px(1), controls_border_color),
+                z_index: 2,
+                display: "inline-block",
+                padding: format!("{} {}",px(10), px(20)),
+                outline: "none",
+                position: "relative",
+                font_size: px(15.75),
+                background_color: controls_content_background_color,
+                line_height: 1,
+                user_select: "none",
+                vertical_align: "middle",
+            },
+
+            img: {
+                display: "inline-block",
+            },
+
+            a: {
+                color: controls_button_text_color,
+                cursor: "pointer",
+                transition: "color 250ms ease-out",
+                text_shadow: format!("{} {} {} {}", 0, 0, px(4), accent_shadow),
+                text_decoration: "none",
+            },
+
+            "a ::selection": {
+                color: "#021114",
+                text_shadow: "none",
+                background_color: secondary_color.clone(),
+            },
+
+            table: {
+                width: percent(100),
+                border_collapse: "collapse",
+                color: secondary_color.clone(),
+            },
+
+            thead: {
+                color: accent_color,
+                text_align: "left",
+                font_family: secondary_font,
+                font_weight: "bold",
+                white_space: "nowrap",
+            },
+
+            tr: {
+                border_bottom: format!("{} solid {}", px(1), controls_border_color),
+            },
+
+            td: {
+                padding: px(5),
+                vertical_align: "top",
+            },
+        };
+
+        let container_css = jss! {
+            ".container": {
+                color: secondary_color.clone(),
+                font_size: px(21),
+                line_height: 1.5,
+                font_family: primary_font,
+                margin: "auto",
+                background_color: background_color.clone(),
+                max_width: em(50),
+                padding: px(10),
+            },
+
+            ".container ::selection": {
+                color: background_color,
+                text_shadow: "none",
+                background_color: secondary_color,
+            },
+
+            ".futuristic-buttons-array": {
+                display: "flex",
+                flex_wrap: "wrap",
+                margin: format!("{} {}", px(20), px(10)),
+            },
+
+            ".more_colors": {
+                display: "flex",
+                flex_direction: "row",
+            },
+
+            ".theme_error": {
+                color: base.error_color(),
+                font_size: px(14),
+            },
+
+            ".theme_presets": {
+                display: "flex",
+                flex_direction: "row",
+                font_size: px(12),
+            },
+
+            ".theme_presets .preset": {
+                margin_right: px(10),
+            },
+
+            ".more_colors .pick": {
+                width: px(10),
+                height: px(10),
+                border_width: px(4),
+                border_style: "solid",
+            }
+        };
+
+        vec![
+            body_css,
+            container_css,
+            icon::style(theme),
+            NavHeader::<Msg>::style(theme),
+            Frame::<Msg>::style(theme),
+            Button::<Msg>::style(theme),
+            TextInput::<Msg>::style(theme),
+            AnimateList::<Msg>::style(theme),
+            Spinner::<Msg>::style(theme),
+            self.image.style(theme),
+        ]
+    }
+
+    fn inject_style(style_class: &str, css: &str) {
+        use sauron::wasm_bindgen::JsCast;
+        let document = sauron::document();
+        let html_style = document
+            .create_element("style")
+            .expect("must be able to create style element");
+        html_style
+            .set_attribute("class", style_class)
+            .expect("must set attribute");
+        let html_style: web_sys::Node = html_style.unchecked_into();
+        html_style.set_text_content(Some(css));
+        let head = document.head().expect("must have a head");
+        head.append_child(&html_style).expect("must append style");
+    }
+
+    fn show_color_selection<MSG>() -> Node<MSG> {
+        let colors = [
+            "#029dbb", "black", "green", "red", "white", "yellow", "purple",
+        ];
+        let backgrounds = ["white", "black"];
+        let mut pairs: Vec<(&str, &str)> = vec![];
+        for primary in colors.iter() {
+            for background in backgrounds.iter() {
+                if primary != background {
+                    pairs.push((primary, background));
+                }
+            }
+        }
+        div(
+            vec![class("more_colors")],
+            pairs.into_iter().map(|(primary,background)|{
+                a(vec![class("colors"), href(format!("#/{}/{}",primary,background))], vec![
+                    div(vec![class("pick"), style!{background_color: primary, border_color: background}], vec![]),
+                ])
+            })
+        )
+    }
+
+    fn show_theme_presets(themes: &ThemeRegistry) -> Node<Msg> {
+        div(
+            [class("theme_presets")],
+            themes.names().map(|preset| {
+                let theme_name = preset.to_string();
+                a(
+                    [
+                        class("preset"),
+                        on_click(move |_| Msg::SetTheme(theme_name.clone())),
+                    ],
+                    [text(preset)],
+                )
+            }),
+        )
+        .add_children([a(
+            [class("preset"), on_click(|_| Msg::FollowSystemScheme)],
+            [text("system")],
+        )])
+    }
+
+    fn animate_list_content() -> Node<Msg> {
+        let long_txt = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Nullam scelerisque purus faucibus urna venenatis, a elementum diam laoreet. Fusce eget enim justo. Pellentesque cursus metus elit, ut porttitor eros iaculis sit amet. Quisque varius felis id turpis iaculis, et viverra enim pulvinar. Curabitur vel lacus interdum, molestie purus ut, pretium nibh. Mauris commodo dolor magna, eget dignissim mauris semper vitae. Ut viverra nec ex quis semper. Sed sit amet tincidunt mauris. Mauris in imperdiet ipsum. Praesent pretium tortor ut felis posuere, sed lacinia nunc pretium. Morbi et felis nec neque accumsan tincidunt. In hac habitasse platea dictumst. Nulla sit amet elit sed purus posuere placerat ut quis metus. Etiam mattis interdum dui at ornare. Nunc sit amet venenatis lorem, sed eleifend mauris. Pellentesque eros sem, fermentum vel lacus at, congue rhoncus elit. ";
+        div(
+            vec![],
+            vec![
+                div(vec![],vec![
+                    table(vec![],vec![
+                        thead(vec![],vec![
+                            tr(vec![],vec![
+                                th(vec![],vec![text("Prop name")]),
+                                th(vec![],vec![text("Type")]),
+                                th(vec![],vec![text("Default")]),
+                                th(vec![],vec![text("Description")]),
+                            ]),
+                        ]),
+                        tbody(vec![],vec![
+                            tr(vec![],vec![
+                                td(vec![],vec![text("name")]),
+                                td(vec![],vec![text("string")]),
+                                td(vec![],vec![text("''")]),
+                                td(vec![],vec![text("The base name of the component")]),
+                            ]),
+                            tr(vec![],vec![
+                                td(vec![],vec![text("age")]),
+                                td(vec![],vec![text("number")]),
+                                td(vec![],vec![text("0")]),
+                                td(vec![],vec![text("The age of the component")]),
+                            ]),
+                            tr(vec![],vec![
+                                td(vec![],vec![text("married")]),
+                                td(vec![],vec![text("bool")]),
+                                td(vec![],vec![text("false")]),
+                                td(vec![],vec![text("If the component is married")]),
+                            ]),
+                        ]),
+                    ]),
+                ]),
+                p(vec![], vec![
+                    text("This is an experimental demo showcasing usage of sauron[0] Application lifecycle to work alongside
+                    css transition, animation and timed DOM manipulation. This is also an exploration on how to add theming to the web framework.
+                    Sauron is a light-weight web framework designed to have you write least amount of code possible."),
+                    a(vec![href("https://github.com/ivanceras/sauron")], vec![text("Link here")]),
+                ]),
+                li(vec![], vec![text(long_txt)]),
+                li(vec![], vec![text("List 2")]),
+                ul(
+                    vec![],
+                    vec![
+                        li(vec![], vec![text("SubList 3")]),
+                        li(vec![], vec![text("Not too long txt here... trying to see if it is correctly animated")]),
+                    ],
+                ),
+            ],
+        )
+    }
+
+    fn reanimate_all() -> Cmd<Self, Msg> {
+        Cmd::from(Effects::with_local(vec![
+            Msg::ReAnimateFrame,
+            Msg::ReAnimateHeader,
+            Msg::ReAnimateParagraph,
+            Msg::ReAnimateList,
+            Msg::StartAnimateImageEffects,
+        ]))
+        .measure()
+    }
+}
+
+#[global_allocator]
+static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+
+#[wasm_bindgen(start)]
+pub fn main() {
+    console_log::init_with_level(log::Level::Trace).unwrap();
+    console_error_panic_hook::set_once();
+    SoundManager::init();
+    let app_container = sauron::document()
+        .get_element_by_id("app_container")
+        .expect("must have the app_container in index.html");
+    Program::replace_mount(App::default(), &app_container);
+}
@@ -1,27 +1,56 @@
-use crate::sounds;
+use crate::{
+    animation::{
+        AnimateOut, AnimateOutListeners, AnimationStart, TypingTicker,
+    },
+    AnimationOptions,
+};
 use sauron::{
     html::{attributes::class, div, text},
     jss,
     prelude::*,
     Node,
 };
-use web_sys::HtmlAudioElement;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone, Debug)]
 pub enum Msg {
     AnimateIn,
+    /// hide the content in reverse
+    AnimateOut,
     StopAnimation,
+    AnimateOutDone,
     NextAnimation(bool, f64, f64),
+    /// freeze the animation at its current progress
+    Pause,
+    /// continue the paused animation
+    Resume,
+    /// jump to this fraction of the animation, from 0.0 to 1.0
+    Seek(f64),
+    /// show the content right away
+    SkipToEnd,
 }
 
 pub struct AnimateList<XMSG> {
-    audio: HtmlAudioElement,
     animated_layer: Option<Node<XMSG>>,
     children: Node<XMSG>,
     animating: bool,
+    /// the content is hidden after animating out
+    hidden: bool,
+    /// the direction of the current animation
+    is_in: bool,
+    start: AnimationStart,
+    /// the elapsed time of the animation when it was paused
+    paused_elapsed: Option<f64>,
     content_len: usize,
+    /// the positions where the animation pauses after an element is typed
+    pauses: Vec<usize>,
+    options: AnimationOptions,
+    /// the number of steps revealed by the last render
+    revealed: usize,
+    typing_ticker: TypingTicker,
     /// these are listeners that will be called when the anination is done
     on_stop_animation: Vec<Callback<(), XMSG>>,
+    on_animate_out: AnimateOutListeners<XMSG>,
 }
 
 impl<XMSG> AnimateList<XMSG>
@@ -29,16 +58,37 @@ where
     XMSG: Clone,
 {
     pub fn with_content(children: Node<XMSG>) -> Self {
-        let content_len = children.node_count();
+        let content_len = Self::content_length(&children);
+        let pauses = Self::element_ends(&children);
         AnimateList {
-            audio: sounds::preload("sounds/typing.mp3"),
             animating: false,
+            hidden: false,
+            is_in: true,
+            start: AnimationStart::default(),
+            paused_elapsed: None,
             animated_layer: None,
             children,
             content_len,
+            pauses,
+            options: AnimationOptions::default(),
+            revealed: 0,
+            typing_ticker: TypingTicker::default(),
             on_stop_animation: vec![],
+            on_animate_out: AnimateOutListeners::default(),
         }
     }
+
+    /// use these options for the timing of the typing animation
+    pub fn with_animation_options(mut self, options: AnimationOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl<XMSG> AnimateOut<XMSG> for AnimateList<XMSG> {
+    fn animate_out_listeners(&mut self) -> &mut AnimateOutListeners<XMSG> {
+        &mut self.on_animate_out
+    }
 }
 
 impl<XMSG> Container<Msg, XMSG> for AnimateList<XMSG>
@@ -48,6 +98,7 @@ where
     fn update(&mut self, msg: Msg) -> Effects<Msg, XMSG> {
         match msg {
             Msg::AnimateIn => Effects::with_local(self.animate_in()),
+            Msg::AnimateOut => Effects::with_local(self.animate_out()),
             Msg::StopAnimation => {
                 self.stop_animation();
                 let pmsg_list = self
@@ -56,10 +107,19 @@ where
                     .map(|listener| listener.emit(()));
                 Effects::with_external(pmsg_list)
             }
+            Msg::AnimateOutDone => {
+                self.stop_animation();
+                self.hidden = true;
+                Effects::with_external(self.on_animate_out.emit())
+            }
             Msg::NextAnimation(is_in, start, duration) => {
                 let follow_ups = self.next_animation(is_in, start, duration);
                 Effects::with_local(follow_ups)
             }
+            Msg::Pause => Effects::with_local(self.pause()),
+            Msg::Resume => Effects::with_local(self.resume()),
+            Msg::Seek(fraction) => Effects::with_local(self.seek(fraction)),
+            Msg::SkipToEnd => Effects::with_local(self.skip_to_end()),
         }
     }
 
@@ -71,7 +131,10 @@ where
             [div(
                 [
                     class("animate_list"),
-                    classes_flag([("animating", self.animating)]),
+                    classes_flag([
+                        ("animating", self.animating),
+                        ("hidden", self.hidden),
+                    ]),
                 ],
                 [
                     div(
@@ -105,16 +168,123 @@ where
     XMSG: Clone,
 {
     pub fn animate_in(&mut self) -> Vec<Msg> {
-        sounds::play(&self.audio);
         self.stop_animation();
+        self.hidden = false;
         self.start_animation(true)
     }
 
+    /// hide the content by typing it in reverse
+    pub fn animate_out(&mut self) -> Vec<Msg> {
+        if self.hidden {
+            return vec![Msg::AnimateOutDone];
+        }
+        self.stop_animation();
+        self.start_animation(false)
+    }
+
     fn stop_animation(&mut self) -> Vec<Msg> {
+        if self.animating && !self.is_paused() {
+            self.options.typing_sound.stop();
+        }
         self.animating = false;
+        self.paused_elapsed = None;
         vec![]
     }
 
+    /// true if the animation is running or paused
+    pub fn is_animating(&self) -> bool {
+        self.animating
+    }
+
+    /// true if the animation is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused_elapsed.is_some()
+    }
+
+    /// freeze the animation at its current progress
+    pub fn pause(&mut self) -> Vec<Msg> {
+        self.pause_at(sauron::dom::now())
+    }
+
+    /// continue the paused animation from where it was paused
+    pub fn resume(&mut self) -> Vec<Msg> {
+        self.resume_at(sauron::dom::now())
+    }
+
+    /// jump to this fraction of the animation, from 0.0 to 1.0.
+    /// When the animation is not running, the content is shown paused at this progress.
+    pub fn seek(&mut self, fraction: f64) -> Vec<Msg> {
+        self.seek_at(fraction, sauron::dom::now())
+    }
+
+    /// end the animation right away, showing the content when animating in
+    /// or hiding it when animating out
+    pub fn skip_to_end(&mut self) -> Vec<Msg> {
+        if !self.animating {
+            return vec![];
+        }
+        self.paused_elapsed = None;
+        if self.is_in {
+            vec![Msg::StopAnimation]
+        } else {
+            vec![Msg::AnimateOutDone]
+        }
+    }
+
+    fn pause_at(&mut self, timestamp: f64) -> Vec<Msg> {
+        if self.animating && self.paused_elapsed.is_none() {
+            self.paused_elapsed = Some(timestamp - self.start.at());
+            self.options.typing_sound.stop();
+        }
+        vec![]
+    }
+
+    fn resume_at(&mut self, timestamp: f64) -> Vec<Msg> {
+        if let Some(elapsed) = self.paused_elapsed.take() {
+            self.start.restart(timestamp - elapsed);
+            self.typing_ticker.reset(self.revealed);
+            self.options.typing_sound.start();
+            vec![Msg::NextAnimation(
+                self.is_in,
+                self.start.at(),
+                self.duration(),
+            )]
+        } else {
+            vec![]
+        }
+    }
+
+    fn seek_at(&mut self, fraction: f64, timestamp: f64) -> Vec<Msg> {
+        if self.content_len == 0 {
+            return vec![];
+        }
+        if !self.animating {
+            self.animating = true;
+            self.hidden = false;
+            self.is_in = true;
+            self.paused_elapsed = Some(0.0);
+        }
+        let elapsed = self.options.start_delay
+            + fraction.clamp(0.0, 1.0) * self.duration();
+        self.render_at(elapsed);
+        if self.paused_elapsed.is_some() {
+            self.paused_elapsed = Some(elapsed);
+            vec![]
+        } else {
+            self.start.restart(timestamp - elapsed);
+            vec![Msg::NextAnimation(
+                self.is_in,
+                self.start.at(),
+                self.duration(),
+            )]
+        }
+    }
+
+    /// the duration of the animation, not counting the start delay
+    fn duration(&self) -> f64 {
+        self.options.duration(self.content_len, self.pauses.len())
+    }
+
     #[allow(unused)]
     pub fn add_stop_animation_listener<F>(&mut self, f: F)
     where
@@ -126,23 +296,94 @@ where
 
     fn start_animation(&mut self, is_in: bool) -> Vec<Msg> {
         if self.content_len == 0 {
-            return vec![];
+            return if is_in {
+                vec![]
+            } else {
+                vec![Msg::AnimateOutDone]
+            };
         }
 
-        let interval = 1_000.0 / 60.0;
-        let real_duration = interval * self.content_len as f64;
-        let timeout = 500.0;
-        let duration = real_duration.min(timeout);
-        let start = crate::dom::now();
+        let duration = self.duration();
+        let start = sauron::dom::now();
 
         self.animating = true;
+        self.is_in = is_in;
+        self.start.restart(start);
+        self.paused_elapsed = None;
         if is_in {
             self.animated_layer = None;
         }
+        self.typing_ticker
+            .reset(if is_in { 0 } else { self.content_len });
+        self.options.typing_sound.start();
 
         vec![Msg::NextAnimation(is_in, start, duration)]
     }
 
+    /// the number of steps it takes to type the whole node,
+    /// counted the same way as `include_node` does:
+    /// each grapheme of the text, each child of the element
+    /// and each of the other leaf nodes.
+    fn content_length(node: &Node<XMSG>) -> usize {
+        match node {
+            Node::Element(element) => {
+                element.children.len()
+                    + element
+                        .children
+                        .iter()
+                        .map(Self::content_length)
+                        .sum::<usize>()
+            }
+            Node::Leaf(Leaf::Text(txt)) => txt.graphemes(true).count(),
+            Node::Leaf(Leaf::Fragment(nodes)) => {
+                nodes.iter().map(Self::content_length).sum()
+            }
+            Node::Leaf(_) => 1,
+        }
+    }
+
+    /// the positions where each of the elements inside the node
+    /// is completely typed, in ascending order
+    fn element_ends(node: &Node<XMSG>) -> Vec<usize> {
+        let mut ends = vec![];
+        let total = Self::collect_element_ends(node, 0, &mut ends);
+        ends.sort_unstable();
+        ends.dedup();
+        // no need to pause when everything is already typed
+        ends.retain(|end| *end < total);
+        ends
+    }
+
+    /// collect the element ends of the node which starts at the offset,
+    /// returns the content length of the node
+    fn collect_element_ends(
+        node: &Node<XMSG>,
+        offset: usize,
+        ends: &mut Vec<usize>,
+    ) -> usize {
+        match node {
+            Node::Element(element) => {
+                let mut position = offset;
+                for child in element.children.iter() {
+                    position +=
+                        Self::collect_element_ends(child, position, ends);
+                }
+                position += element.children.len();
+                ends.push(position);
+                position - offset
+            }
+            Node::Leaf(Leaf::Fragment(nodes)) => {
+                let mut position = offset;
+                for node in nodes {
+                    position +=
+                        Self::collect_element_ends(node, position, ends);
+                }
+                position - offset
+            }
+            _ => Self::content_length(node),
+        }
+    }
+
     /// include the the element from the src to dest
     /// as long as its current_cnt is less than the chars_limit
     fn include_node(
@@ -187,7 +428,7 @@ where
                         .len()
                         - 1;
 
-                    let mut just_added_child = dest
+                    let just_added_child = dest
                         .children_mut()
                         .expect("must have children, since just added 1")
                         .get_mut(last_index)
@@ -195,7 +436,7 @@ where
 
                     for child in &element.children[0..truncate_len] {
                         Self::include_node_recursive(
-                            &mut just_added_child,
+                            just_added_child,
                             child,
                             chars_limit,
                             current_cnt,
@@ -205,7 +446,7 @@ where
                 }
             }
             Node::Leaf(Leaf::Text(txt)) => {
-                let txt_len = txt.len();
+                let txt_len = txt.graphemes(true).count();
                 let truncate_len = if chars_limit > *current_cnt {
                     std::cmp::min(txt_len, chars_limit - *current_cnt)
                 } else {
@@ -213,7 +454,14 @@ where
                 };
 
                 if truncate_len > 0 {
-                    let truncated_txt = &txt[0..truncate_len];
+                    // slice at the grapheme boundary, so multi-byte characters
+                    // and combining marks are never cut in the middle
+                    let end = txt
+                        .grapheme_indices(true)
+                        .nth(truncate_len)
+                        .map(|(index, _)| index)
+                        .unwrap_or(txt.len());
+                    let truncated_txt = &txt[0..end];
                     let text_node = text(truncated_txt);
                     dest.add_children_ref_mut([text_node]);
                     // we append the blinking character to the end of the text
@@ -233,6 +481,19 @@ where
                 dest.add_children_ref_mut([safe_html(html_text)]);
                 *current_cnt += 1;
             }
+            Node::Leaf(Leaf::Fragment(nodes)) => {
+                for node in nodes {
+                    Self::include_node_recursive(
+                        dest,
+                        node,
+                        chars_limit,
+                        current_cnt,
+                    );
+                }
+            }
+            Node::Leaf(Leaf::DocType(_)) => {
+                *current_cnt += 1;
+            }
         }
     }
 
@@ -242,31 +503,56 @@ where
         start: f64,
         duration: f64,
     ) -> Vec<Msg> {
-        let timestamp = crate::dom::now();
+        self.next_animation_at(is_in, start, duration, sauron::dom::now())
+    }
 
-        let mut anim_progress = (timestamp - start).max(0.0);
-        if !is_in {
-            anim_progress = duration - anim_progress;
+    fn next_animation_at(
+        &mut self,
+        is_in: bool,
+        start: f64,
+        duration: f64,
+        timestamp: f64,
+    ) -> Vec<Msg> {
+        // the loop of an animation which is stopped or paused
+        if !self.animating || self.is_paused() || self.start.is_stale(start) {
+            return vec![];
         }
+        let continue_animation = self.render_at(timestamp - start);
+        self.options.typing_sound.revealed(
+            &mut self.typing_ticker,
+            self.revealed,
+            timestamp,
+        );
 
-        let new_length = (anim_progress * self.content_len as f64 / duration)
-            .round() as usize;
+        if continue_animation {
+            vec![Msg::NextAnimation(is_in, start, duration)]
+        } else if is_in {
+            vec![Msg::StopAnimation]
+        } else {
+            vec![Msg::AnimateOutDone]
+        }
+    }
+
+    /// render the animated layer at this elapsed time of the animation,
+    /// returns true if the animation is not done yet
+    fn render_at(&mut self, elapsed: f64) -> bool {
+        let new_length = self.options.revealed(
+            elapsed,
+            self.is_in,
+            self.content_len,
+            &self.pauses,
+        );
 
         let mut dest: Node<XMSG> = div([], []);
 
         Self::include_node(&mut dest, &self.children, new_length);
         self.animated_layer = Some(dest);
+        self.revealed = new_length;
 
-        let continue_animation = if is_in {
-            new_length <= (self.content_len - 1)
+        if self.is_in {
+            new_length < self.content_len
         } else {
             new_length > 0
-        };
-
-        if continue_animation {
-            vec![Msg::NextAnimation(is_in, start, duration)]
-        } else {
-            vec![Msg::StopAnimation]
         }
     }
 
@@ -313,6 +599,10 @@ where
                 opacity: 1,
             },
 
+            ".hidden .animate_list_children": {
+                visibility: "hidden",
+            },
+
             "@keyframes animate_list_blink-anim": {
                 "0%, 100%": {
                   color: "transparent",
@@ -325,3 +615,6 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
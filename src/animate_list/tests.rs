@@ -0,0 +1,220 @@
+use super::*;
+use crate::TypingSound;
+
+/// the markup of the animated layer when `chars_limit` graphemes are typed
+fn typed(src: &Node<()>, chars_limit: usize) -> String {
+    let mut dest: Node<()> = div([], []);
+    AnimateList::include_node(&mut dest, src, chars_limit);
+    dest.render_to_string()
+}
+
+#[test]
+fn ascii_text_is_typed_partially() {
+    let src = p([], [text("hello")]);
+    assert_eq!(
+        typed(&src, 3),
+        "<div><p>hel<span class=\"blink\">█</span></p></div>"
+    );
+}
+
+#[test]
+fn completely_typed_text_has_no_blink() {
+    let src = p([], [text("hello")]);
+    let len = AnimateList::content_length(&src);
+    assert_eq!(len, 6);
+    assert_eq!(typed(&src, len), "<div><p>hello</p></div>");
+}
+
+#[test]
+fn cjk_text() {
+    let src = p([], [text("你好世界")]);
+    assert_eq!(AnimateList::content_length(&src), 5);
+    assert_eq!(
+        typed(&src, 2),
+        "<div><p>你好<span class=\"blink\">█</span></p></div>"
+    );
+}
+
+#[test]
+fn emoji_sequence_is_a_single_step() {
+    let family = "👨\u{200d}👩\u{200d}👧";
+    let src = p([], [text(format!("{}ok", family))]);
+    assert_eq!(AnimateList::content_length(&src), 4);
+    assert_eq!(
+        typed(&src, 1),
+        format!("<div><p>{}<span class=\"blink\">█</span></p></div>", family)
+    );
+}
+
+#[test]
+fn combining_accent_stays_with_its_base() {
+    let src = p([], [text("e\u{301}te")]);
+    assert_eq!(
+        typed(&src, 1),
+        "<div><p>e\u{301}<span class=\"blink\">█</span></p></div>"
+    );
+}
+
+#[test]
+fn rtl_text() {
+    let src = p([], [text("שלום")]);
+    assert_eq!(
+        typed(&src, 2),
+        "<div><p>של<span class=\"blink\">█</span></p></div>"
+    );
+}
+
+#[test]
+fn every_step_of_multi_byte_content_is_valid() {
+    let src = div(
+        [],
+        [
+            h1([], [text("Ünïcödé 日本語")]),
+            p(
+                [],
+                [text("🚀 launch "), b([], [text("मराठी")]), text(" done")],
+            ),
+        ],
+    );
+    let len = AnimateList::content_length(&src);
+    for limit in 0..=len {
+        typed(&src, limit);
+    }
+    assert_eq!(
+        typed(&src, len),
+        format!("<div>{}</div>", src.render_to_string())
+    );
+}
+
+#[test]
+fn pauses_after_each_element() {
+    let src = div(
+        [],
+        [p([], [text("ab")]), p([], [text("cd"), b([], [text("e")])])],
+    );
+    // the first p ends after its 2 graphemes and its 1 child,
+    // the b comes after the 2 graphemes and the separator comment
+    // which sauron inserts between a text and an element
+    assert_eq!(AnimateList::<()>::element_ends(&src), vec![3, 8, 11]);
+    assert_eq!(AnimateList::<()>::content_length(&src), 13);
+}
+
+#[test]
+fn animate_out_done_hides_and_calls_only_the_animate_out_listeners() {
+    let mut list = AnimateList::with_content(p([], [text("bye")]));
+    list.add_stop_animation_listener(|_| "stopped");
+    list.add_animate_out_listener(|_| "hidden");
+
+    let (local, external) = list.update(Msg::AnimateOutDone).unzip();
+    assert!(local.is_empty());
+    assert_eq!(external, vec!["hidden"]);
+    assert!(list
+        .view()
+        .render_to_string()
+        .contains("animate_list hidden"));
+
+    // animating out an already hidden list completes right away
+    assert!(matches!(list.animate_out()[..], [Msg::AnimateOutDone]));
+}
+
+/// an animation of 10 graphemes which takes 1000ms
+fn ten_chars() -> AnimateList<()> {
+    let mut list = AnimateList::with_content(text("0123456789"))
+        .with_animation_options(AnimationOptions {
+            chars_per_second: 10.0,
+            max_duration: f64::INFINITY,
+            ..Default::default()
+        });
+    list.animating = true;
+    list.start.restart(1000.0);
+    list
+}
+
+fn layer(list: &AnimateList<()>) -> String {
+    list.animated_layer
+        .as_ref()
+        .expect("must have a layer")
+        .render_to_string()
+}
+
+#[test]
+fn pause_and_resume() {
+    let mut list = ten_chars();
+    assert!(list.next_animation_at(true, 1000.0, 1000.0, 1300.0).len() == 1);
+    assert!(layer(&list).contains("012<span"));
+
+    list.pause_at(1300.0);
+    assert!(list.is_paused());
+    // the pending loop stops while paused
+    assert!(list
+        .next_animation_at(true, 1000.0, 1000.0, 1500.0)
+        .is_empty());
+
+    let follow_ups = list.resume_at(5000.0);
+    assert!(matches!(
+        follow_ups[..],
+        [Msg::NextAnimation(true, start, _)] if start == 4700.0
+    ));
+    assert!(!list.is_paused());
+    // the previous loop is ignored, while the resumed one continues
+    assert!(list
+        .next_animation_at(true, 1000.0, 1000.0, 5100.0)
+        .is_empty());
+    list.next_animation_at(true, 4700.0, 1000.0, 5100.0);
+    assert!(layer(&list).contains("0123<span"));
+}
+
+#[test]
+fn seek_while_playing_and_paused() {
+    let mut list = ten_chars();
+    let follow_ups = list.seek_at(0.5, 2000.0);
+    assert!(matches!(
+        follow_ups[..],
+        [Msg::NextAnimation(true, start, _)] if start == 1500.0
+    ));
+    assert!(layer(&list).contains("01234<span"));
+
+    list.pause_at(1600.0);
+    assert!(list.seek_at(0.8, 3000.0).is_empty());
+    assert!(list.is_paused());
+    assert!(layer(&list).contains("01234567<span"));
+}
+
+#[test]
+fn seek_when_not_animating_shows_it_paused() {
+    let mut list = AnimateList::<()>::with_content(text("0123456789"));
+    assert!(list.seek_at(1.0, 0.0).is_empty());
+    assert!(list.is_animating());
+    assert!(list.is_paused());
+    assert!(layer(&list).contains("0123456789"));
+    assert!(!layer(&list).contains("blink"));
+}
+
+#[test]
+fn skip_to_end() {
+    let mut list = ten_chars();
+    assert!(matches!(list.skip_to_end()[..], [Msg::StopAnimation]));
+    list.update(Msg::StopAnimation);
+    assert!(!list.is_animating());
+    assert!(list.skip_to_end().is_empty());
+
+    let mut list = ten_chars();
+    list.is_in = false;
+    assert!(matches!(list.skip_to_end()[..], [Msg::AnimateOutDone]));
+}
+
+#[test]
+fn typing_ticker_follows_the_revealed_steps() {
+    let mut list = ten_chars();
+    list.options.typing_sound = TypingSound::Tick { min_interval: 50.0 };
+    list.next_animation_at(true, 1000.0, 1000.0, 1300.0);
+    assert_eq!(list.revealed, 3);
+    // the next step is revealed within the minimum interval of the previous tick
+    assert!(!list.typing_ticker.tick(4, 1320.0, 50.0));
+
+    // a resumed animation counts from the steps already revealed
+    list.pause_at(1300.0);
+    list.resume_at(2000.0);
+    assert!(!list.typing_ticker.tick(3, 2000.0, 50.0));
+    assert!(list.typing_ticker.tick(4, 2100.0, 50.0));
+}
@@ -0,0 +1,286 @@
+//! The timing of the typing and slice reveal animations.
+use crate::sound_theme::SoundEvent;
+use sauron::prelude::Callback;
+
+/// How fast the content of `AnimateList`, `Paragraph` and `Image` is revealed.
+///
+/// The content is measured in steps: graphemes of the text and elements
+/// for the `AnimateList`, slices for the `Image`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationOptions {
+    /// the number of steps revealed per second
+    pub chars_per_second: f64,
+    /// the animation is sped up to finish within this duration in ms,
+    /// not counting the start delay
+    pub max_duration: f64,
+    /// the easing curve of the progress
+    pub easing: Easing,
+    /// the delay in ms before the first step is revealed
+    pub start_delay: f64,
+    /// the pause in ms after each element is completely revealed
+    pub element_pause: f64,
+    /// how the typing sound follows the animation
+    pub typing_sound: TypingSound,
+}
+
+/// How the typing sound follows the progress of the animation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypingSound {
+    /// the animation is silent
+    Off,
+    /// play the typing sound once when the animation starts
+    Once,
+    /// tick for the revealed steps, at most once every `min_interval` ms
+    Tick { min_interval: f64 },
+    /// loop the typing sound while the animation runs
+    Loop,
+}
+
+/// Decides when the typing sound ticks as the steps are revealed
+#[derive(Debug, Default)]
+pub(crate) struct TypingTicker {
+    revealed: usize,
+    last_tick: Option<f64>,
+}
+
+/// The time the current animation of a widget started.
+///
+/// Each loop of an animation carries the time its animation started,
+/// so the loops of the previous animations are dropped when the animation is restarted.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct AnimationStart(f64);
+
+/// A widget which calls its listeners when it is completely hidden by the animate out
+pub trait AnimateOut<XMSG> {
+    /// the listeners called when the animate out is done
+    fn animate_out_listeners(&mut self) -> &mut AnimateOutListeners<XMSG>;
+
+    /// the listener is called when the widget is completely hidden by the animate out
+    fn add_animate_out_listener<F>(&mut self, f: F)
+    where
+        F: Fn(()) -> XMSG + 'static,
+    {
+        self.animate_out_listeners().0.push(Callback::from(f));
+    }
+}
+
+/// The listeners of a widget which are called when the animate out is done
+pub struct AnimateOutListeners<XMSG>(Vec<Callback<(), XMSG>>);
+
+/// The easing curve applied to the linear progress of the animation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl Easing {
+    /// map the linear progress `t` from 0.0 to 1.0 into the eased progress
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+        }
+    }
+}
+
+impl AnimationOptions {
+    /// the time in ms it takes to reveal one step, without the `max_duration` cap
+    fn step_duration(&self) -> f64 {
+        if self.chars_per_second > 0.0 {
+            1_000.0 / self.chars_per_second
+        } else {
+            0.0
+        }
+    }
+
+    /// the time in ms to reveal the content and its pauses, without the `max_duration` cap
+    fn natural_duration(&self, content_len: usize, pause_count: usize) -> f64 {
+        content_len as f64 * self.step_duration()
+            + pause_count as f64 * self.element_pause
+    }
+
+    /// the duration in ms of the animation, not counting the start delay
+    pub fn duration(&self, content_len: usize, pause_count: usize) -> f64 {
+        self.natural_duration(content_len, pause_count)
+            .min(self.max_duration)
+            .max(0.0)
+    }
+
+    /// the number of steps revealed at `elapsed` ms since the start of the animation.
+    /// The animation pauses when it reaches each of the ascending `pauses` positions.
+    /// When animating out, the content is hidden in reverse.
+    pub fn revealed(
+        &self,
+        elapsed: f64,
+        is_in: bool,
+        content_len: usize,
+        pauses: &[usize],
+    ) -> usize {
+        let duration = self.duration(content_len, pauses.len());
+        let elapsed = (elapsed - self.start_delay).max(0.0);
+        let elapsed = if is_in {
+            elapsed
+        } else {
+            (duration - elapsed).max(0.0)
+        };
+        if duration <= 0.0 {
+            return if is_in { content_len } else { 0 };
+        }
+        let progress = self.easing.apply(elapsed / duration);
+
+        // walk the uncapped timeline, where each step and pause
+        // has its own natural duration
+        let step = self.step_duration();
+        let mut remaining =
+            progress * self.natural_duration(content_len, pauses.len());
+        let mut position = 0;
+        for &pause_at in pauses {
+            let pause_at = pause_at.clamp(position, content_len);
+            let typing = (pause_at - position) as f64 * step;
+            if remaining < typing {
+                break;
+            }
+            remaining -= typing;
+            position = pause_at;
+            if remaining < self.element_pause {
+                return position;
+            }
+            remaining -= self.element_pause;
+        }
+        let typed = if step > 0.0 {
+            (remaining / step).round() as usize
+        } else {
+            content_len
+        };
+        (position + typed).min(content_len)
+    }
+}
+
+impl AnimationStart {
+    /// the current animation starts at this timestamp
+    pub(crate) fn restart(&mut self, start: f64) {
+        self.0 = start;
+    }
+
+    /// the timestamp the current animation started
+    pub(crate) fn at(&self) -> f64 {
+        self.0
+    }
+
+    /// true if the loop is of an animation started at `start`, which is restarted since
+    pub(crate) fn is_stale(&self, start: f64) -> bool {
+        start != self.0
+    }
+}
+
+impl<XMSG> AnimateOutListeners<XMSG> {
+    /// call the listeners, the animate out is done
+    pub(crate) fn emit(&self) -> Vec<XMSG> {
+        self.0.iter().map(|listener| listener.emit(())).collect()
+    }
+}
+
+impl<XMSG> Default for AnimateOutListeners<XMSG> {
+    fn default() -> Self {
+        AnimateOutListeners(vec![])
+    }
+}
+
+impl TypingSound {
+    /// the animation starts or resumes
+    pub(crate) fn start(&self) {
+        match self {
+            TypingSound::Off => (),
+            TypingSound::Once => SoundEvent::Typing.play(),
+            TypingSound::Tick { .. } => (),
+            TypingSound::Loop => SoundEvent::Typing.play_looped(),
+        }
+    }
+
+    /// the animation is stopped or paused
+    pub(crate) fn stop(&self) {
+        if *self != TypingSound::Off {
+            SoundEvent::Typing.stop();
+        }
+    }
+
+    /// the animation has revealed this many steps at the timestamp
+    pub(crate) fn revealed(
+        &self,
+        ticker: &mut TypingTicker,
+        revealed: usize,
+        timestamp: f64,
+    ) {
+        if let TypingSound::Tick { min_interval } = self {
+            if ticker.tick(revealed, timestamp, *min_interval) {
+                SoundEvent::Typing.play();
+            }
+        }
+    }
+}
+
+/// play the typing sound once per animation
+impl Default for TypingSound {
+    fn default() -> Self {
+        TypingSound::Once
+    }
+}
+
+impl TypingTicker {
+    /// start counting from the steps revealed at the start of the animation
+    pub(crate) fn reset(&mut self, revealed: usize) {
+        self.revealed = revealed;
+        self.last_tick = None;
+    }
+
+    /// true if the sound should tick, which is when more steps are revealed or hidden
+    /// and the last tick was at least `min_interval` ms ago
+    pub(crate) fn tick(
+        &mut self,
+        revealed: usize,
+        timestamp: f64,
+        min_interval: f64,
+    ) -> bool {
+        if revealed == self.revealed {
+            return false;
+        }
+        let is_due = match self.last_tick {
+            Some(last_tick) => timestamp - last_tick >= min_interval,
+            None => true,
+        };
+        if is_due {
+            self.revealed = revealed;
+            self.last_tick = Some(timestamp);
+        }
+        is_due
+    }
+}
+
+/// 60 steps per second, finishing within 500ms
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        AnimationOptions {
+            chars_per_second: 60.0,
+            max_duration: 500.0,
+            easing: Easing::Linear,
+            start_delay: 0.0,
+            element_pause: 0.0,
+            typing_sound: TypingSound::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,157 @@
+use super::*;
+
+#[test]
+fn default_matches_60_steps_per_second_capped_at_500ms() {
+    let options = AnimationOptions::default();
+    assert_eq!(options.duration(6, 0), 100.0);
+    assert_eq!(options.duration(1000, 0), 500.0);
+}
+
+#[test]
+fn long_content_takes_longer_when_uncapped() {
+    let options = AnimationOptions {
+        chars_per_second: 100.0,
+        max_duration: f64::INFINITY,
+        ..Default::default()
+    };
+    assert_eq!(options.duration(10, 0), 100.0);
+    assert_eq!(options.duration(1000, 0), 10_000.0);
+}
+
+#[test]
+fn linear_progress() {
+    let options = AnimationOptions {
+        chars_per_second: 10.0,
+        max_duration: f64::INFINITY,
+        ..Default::default()
+    };
+    assert_eq!(options.revealed(0.0, true, 10, &[]), 0);
+    assert_eq!(options.revealed(500.0, true, 10, &[]), 5);
+    assert_eq!(options.revealed(1000.0, true, 10, &[]), 10);
+    assert_eq!(options.revealed(5000.0, true, 10, &[]), 10);
+}
+
+#[test]
+fn capped_duration_speeds_up_the_steps() {
+    let options = AnimationOptions {
+        chars_per_second: 10.0,
+        max_duration: 100.0,
+        ..Default::default()
+    };
+    assert_eq!(options.revealed(50.0, true, 10, &[]), 5);
+    assert_eq!(options.revealed(100.0, true, 10, &[]), 10);
+}
+
+#[test]
+fn animating_out_reverses() {
+    let options = AnimationOptions {
+        chars_per_second: 10.0,
+        max_duration: f64::INFINITY,
+        ..Default::default()
+    };
+    assert_eq!(options.revealed(0.0, false, 10, &[]), 10);
+    assert_eq!(options.revealed(300.0, false, 10, &[]), 7);
+    assert_eq!(options.revealed(1000.0, false, 10, &[]), 0);
+}
+
+#[test]
+fn start_delay() {
+    let options = AnimationOptions {
+        chars_per_second: 10.0,
+        max_duration: f64::INFINITY,
+        start_delay: 200.0,
+        ..Default::default()
+    };
+    assert_eq!(options.revealed(100.0, true, 10, &[]), 0);
+    assert_eq!(options.revealed(200.0, true, 10, &[]), 0);
+    assert_eq!(options.revealed(500.0, true, 10, &[]), 3);
+    assert_eq!(options.revealed(1200.0, true, 10, &[]), 10);
+}
+
+#[test]
+fn element_pause() {
+    let options = AnimationOptions {
+        chars_per_second: 10.0,
+        max_duration: f64::INFINITY,
+        element_pause: 300.0,
+        ..Default::default()
+    };
+    assert_eq!(options.duration(10, 1), 1300.0);
+    assert_eq!(options.revealed(400.0, true, 10, &[4]), 4);
+    assert_eq!(options.revealed(600.0, true, 10, &[4]), 4);
+    assert_eq!(options.revealed(800.0, true, 10, &[4]), 5);
+    assert_eq!(options.revealed(1300.0, true, 10, &[4]), 10);
+}
+
+#[test]
+fn easing_curves() {
+    for easing in [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+    ] {
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+    assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+    assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+    assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    assert!(Easing::EaseInOut.apply(0.25) < 0.25);
+}
+
+#[test]
+fn eased_progress() {
+    let options = AnimationOptions {
+        chars_per_second: 10.0,
+        max_duration: f64::INFINITY,
+        easing: Easing::EaseIn,
+        ..Default::default()
+    };
+    assert_eq!(options.revealed(500.0, true, 10, &[]), 3);
+    assert_eq!(options.revealed(1000.0, true, 10, &[]), 10);
+}
+
+#[test]
+fn zero_speed_reveals_at_once() {
+    let options = AnimationOptions {
+        chars_per_second: 0.0,
+        ..Default::default()
+    };
+    assert_eq!(options.revealed(0.0, true, 10, &[]), 10);
+    assert_eq!(options.revealed(0.0, false, 10, &[]), 0);
+}
+
+#[test]
+fn ticks_when_more_steps_are_revealed() {
+    let mut ticker = TypingTicker::default();
+    ticker.reset(0);
+    assert!(!ticker.tick(0, 0.0, 50.0));
+    assert!(ticker.tick(1, 10.0, 50.0));
+    assert!(!ticker.tick(1, 100.0, 50.0));
+}
+
+#[test]
+fn ticks_are_rate_limited() {
+    let mut ticker = TypingTicker::default();
+    ticker.reset(0);
+    let ticks = (1..=60)
+        .filter(|step| ticker.tick(*step, *step as f64 * 10.0, 50.0))
+        .count();
+    // a step every 10ms for 600ms ticks every 50ms
+    assert_eq!(ticks, 12);
+}
+
+#[test]
+fn ticks_when_hiding_in_reverse() {
+    let mut ticker = TypingTicker::default();
+    ticker.reset(10);
+    assert!(ticker.tick(9, 0.0, 50.0));
+    assert!(!ticker.tick(8, 20.0, 50.0));
+    assert!(ticker.tick(7, 60.0, 50.0));
+}
+
+#[test]
+fn typing_sound_plays_once_by_default() {
+    assert_eq!(AnimationOptions::default().typing_sound, TypingSound::Once);
+}
@@ -1,20 +1,24 @@
-use crate::sounds;
-use css_colors::Color;
+use crate::{
+    decorated_container::{DecoratedContainer, Dimension},
+    shape::ChippedShape,
+    sound_theme::SoundEvent,
+};
 use sauron::jss_ns;
 use sauron::wasm_bindgen::JsCast;
 use sauron::{
     dom::Callback,
     html::attributes,
-    html::{attributes::class, div, events::on_click, text},
+    html::{div, events::on_click, text},
     prelude::*,
     Node,
 };
-use web_sys::HtmlAudioElement;
 use web_sys::MouseEvent;
 
 const COMPONENT_NAME: &str = "fui-button";
 const DEFAULT_CHIPPED_BUTTON_WIDTH: usize = 200;
 const DEFAULT_CHIPPED_BUTTON_HEIGHT: usize = 40;
+const DEFAULT_CHIP_SIZE: f64 = 20.0;
+const ICON_ONLY_BUTTON_SIZE: usize = 40;
 
 #[derive(Clone, Debug)]
 pub enum Msg {
@@ -27,7 +31,6 @@ pub enum Msg {
 
 #[derive(Debug)]
 pub struct Button<PMSG> {
-    audio: HtmlAudioElement,
     options: Options,
     label: String,
     click: bool,
@@ -35,6 +38,18 @@ pub struct Button<PMSG> {
     click_listeners: Vec<Callback<MouseEvent, PMSG>>,
     pub width: Option<usize>,
     pub height: Option<usize>,
+    /// the size of the chipped button measured when mounted,
+    /// used when the width or height is not set
+    measured_size: Option<(usize, usize)>,
+    /// the corners that are cut when the button is chipped
+    chipped_shape: ChippedShape,
+    /// the icon shown before the label
+    leading_icon: Option<Node<Msg>>,
+    /// the icon shown after the label
+    trailing_icon: Option<Node<Msg>>,
+    /// only the icon is shown in a square button,
+    /// the label is used as the accessible name of the button
+    icon_only: bool,
     component_id: Option<String>,
 }
 
@@ -55,6 +70,16 @@ impl Pallete {
             Pallete::Warning => "warning",
         }
     }
+
+    /// the sound of the buttons with this pallete when clicked
+    fn sound_event(&self) -> SoundEvent {
+        match self {
+            Pallete::Error => SoundEvent::Error,
+            Pallete::Success => SoundEvent::Success,
+            Pallete::Info => SoundEvent::Click,
+            Pallete::Warning => SoundEvent::Warning,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -90,7 +115,6 @@ where
     pub fn with_label(label: &str) -> Self {
         let options = Options::regular();
         Button {
-            audio: sounds::preload("sounds/click.mp3"),
             options,
             click: false,
             hover: false,
@@ -98,10 +122,58 @@ where
             click_listeners: vec![],
             width: None,
             height: None,
+            measured_size: None,
+            chipped_shape: ChippedShape::bottom_right(DEFAULT_CHIP_SIZE),
+            leading_icon: None,
+            trailing_icon: None,
+            icon_only: false,
             component_id: None,
         }
     }
 
+    /// the sound played when the button is clicked, which depends on its pallete
+    fn click_sound(&self) -> SoundEvent {
+        self.options
+            .pallete
+            .as_ref()
+            .map(Pallete::sound_event)
+            .unwrap_or(SoundEvent::Click)
+    }
+
+    /// the icons and the label inside the button
+    fn view_label(&self) -> Vec<Node<Msg>> {
+        let class_ns = |class_names| {
+            attributes::class_namespaced(COMPONENT_NAME, class_names)
+        };
+        let icon_slot = |icon: &Option<Node<Msg>>, class_names| {
+            if let Some(icon) = icon {
+                span([class_ns(class_names)], [icon.clone()])
+            } else {
+                comment("icon")
+            }
+        };
+        vec![
+            icon_slot(&self.leading_icon, "icon icon-leading"),
+            view_if(
+                !self.icon_only,
+                span([class_ns("label")], [text(&self.label)]),
+            ),
+            icon_slot(&self.trailing_icon, "icon icon-trailing"),
+        ]
+    }
+
+    /// the label is the accessible name of an icon only button
+    fn accessible_label(&self) -> Vec<Attribute<Msg>> {
+        if self.icon_only {
+            vec![
+                attr("aria-label", self.label.clone()),
+                attr("title", self.label.clone()),
+            ]
+        } else {
+            vec![]
+        }
+    }
+
     fn view_actual_button(
         &self,
         width: Option<usize>,
@@ -129,44 +201,45 @@ where
                 } else {
                     empty_attr()
                 },
-            ],
-            [text(&self.label)],
+            ]
+            .into_iter()
+            .chain(self.accessible_label()),
+            self.view_label(),
+        )
+    }
+
+    /// the width and height of the chipped shape, the configured size takes precedence
+    /// over the size measured from the label, falling back to the default size
+    /// before the button is mounted.
+    fn chipped_size(&self) -> (usize, usize) {
+        let (measured_width, measured_height) = match self.measured_size {
+            Some((width, height)) => (Some(width), Some(height)),
+            None => (None, None),
+        };
+        (
+            self.width
+                .or(measured_width)
+                .unwrap_or(DEFAULT_CHIPPED_BUTTON_WIDTH),
+            self.height
+                .or(measured_height)
+                .unwrap_or(DEFAULT_CHIPPED_BUTTON_HEIGHT),
         )
     }
 
     fn view_chipped_button(&self) -> Node<Msg> {
-        let width = DEFAULT_CHIPPED_BUTTON_WIDTH;
-        let height = DEFAULT_CHIPPED_BUTTON_HEIGHT;
-        let (chip_width, chip_height) = (20, 20);
-        let (gap_x, gap_y) = (4, 4);
-        let top_left = (0, 0);
-        let top_right = (width, 0);
-        let bottom_left = (0, height);
-        let chip1 = (width - chip_width, height);
-        let chip2 = (width, height - chip_height);
-
-        let poly_points = [bottom_left, chip1, chip2, top_right, top_left];
-
-        let bottom_right = (width, height);
-        let tri_edge1 = (width - chip_width + gap_x, height);
-        let tri_edge2 = (width, height - chip_height + gap_y);
-        let triangle = [tri_edge1, tri_edge2, bottom_right];
-
-        let poly_points_str = poly_points
-            .iter()
-            .map(|p| format!("{},{}", p.0, p.1))
-            .collect::<Vec<_>>()
-            .join(" ");
-
-        let triangle_points = triangle
-            .iter()
-            .map(|p| format!("{},{}", p.0, p.1))
-            .collect::<Vec<_>>()
-            .join(" ");
+        let (width, height) = self.chipped_size();
+        let gap = 4.0;
+        let poly_points =
+            self.chipped_shape.svg_points(width as f64, height as f64);
+        let triangles =
+            self.chipped_shape
+                .triangles(width as f64, height as f64, gap);
 
         let class_ns = |class_names| {
             attributes::class_namespaced(COMPONENT_NAME, class_names)
         };
+        // the button sizes the wrapper to its label unless the size is configured,
+        // the svg then stretches to the wrapper
         div(
             [class_ns("chipped_wrapper")],
             [
@@ -177,29 +250,42 @@ where
                         class_ns("chipped_svg"),
                         viewBox([0, 0, width, height]),
                     ],
-                    [
-                        polygon(
-                            [
-                                class_ns("chipped_polygon"),
-                                points(poly_points_str),
-                                on_transitionend(|_| Msg::HighlightEnd),
-                            ],
-                            [],
-                        ),
-                        polygon(
-                            [class_ns("triangle"), points(triangle_points)],
-                            [],
-                        ),
-                    ],
+                    [polygon(
+                        [
+                            class_ns("chipped_polygon"),
+                            points(poly_points),
+                            on_transitionend(|_| Msg::HighlightEnd),
+                        ],
+                        [],
+                    )]
+                    .into_iter()
+                    .chain(triangles.into_iter().map(
+                        |triangle_points| {
+                            polygon(
+                                [class_ns("triangle"), points(triangle_points)],
+                                [],
+                            )
+                        },
+                    )),
                 ),
                 button(
                     [
                         class_ns("chipped_button"),
                         disabled(self.options.disabled),
-                        style! {width: px(width)},
-                        style! {height: px(height)},
-                    ],
-                    [text(&self.label)],
+                        if let Some(width) = self.width {
+                            style! {width: px(width)}
+                        } else {
+                            empty_attr()
+                        },
+                        if let Some(height) = self.height {
+                            style! {height: px(height)}
+                        } else {
+                            empty_attr()
+                        },
+                    ]
+                    .into_iter()
+                    .chain(self.accessible_label()),
+                    self.view_label(),
                 ),
             ],
         )
@@ -218,7 +304,7 @@ where
         match msg {
             Msg::Click(mouse_event) => {
                 if self.options.sound {
-                    sounds::play(&self.audio);
+                    self.click_sound().play();
                 }
                 self.click = true;
                 let pmsg_list = self
@@ -228,6 +314,9 @@ where
                 Effects::with_external(pmsg_list)
             }
             Msg::HoverIn => {
+                if self.options.sound && !self.hover {
+                    SoundEvent::Hover.play();
+                }
                 self.hover = true;
                 Effects::none()
             }
@@ -250,6 +339,9 @@ where
                     log::trace!("mounted: {}", vdom_id);
                     self.component_id = Some(vdom_id);
                 }
+                if self.options.chipped {
+                    self.measure_chipped_button(target_elm);
+                }
                 Effects::none()
             }
         }
@@ -267,78 +359,41 @@ where
             )
         };
 
-        div(
-            [
-                class(COMPONENT_NAME),
-                classes_ns_flag([
-                    ("clicked", self.click),
-                    ("click_highlights", self.options.click_highlights),
-                    ("expand_corners", self.options.expand_corners),
-                    ("has_hover", self.options.has_hover),
-                    ("hovered", self.hover),
-                    ("skewed", self.options.skewed),
-                    ("chipped", self.options.chipped),
-                    // setting this will also disable the div, therefore will not activate the
-                    // events on it
-                    ("disabled", self.options.disabled),
-                    ("hidden", self.options.hidden),
-                ]),
-                if let Some(ref pallete) = self.options.pallete {
-                    class_ns(pallete.class_name())
-                } else {
-                    empty_attr()
-                },
-                // normally click should be attached to the actual button element
-                on_click(Msg::Click),
-                // the mouseover events are attached here since the hover element z-index is
-                // higher than the actual button, which will cause a janky animation
-                // when the mouse is triggering alt hover in and out, since covered by the hover
-                // layer effect
-                on_mouseover(|_| Msg::HoverIn),
-                on_mouseout(|_| Msg::HoverOut),
-                on_mount(|e| Msg::Mounted(e)),
-            ],
-            [
-                // hover
-                view_if(
-                    self.options.has_hover,
-                    div([class_ns("hover hover-bottom")], []),
-                ),
-                //borders
-                view_if(
-                    self.options.has_borders,
-                    div([class_ns("border border-left")], []),
-                ),
-                view_if(
-                    self.options.has_borders,
-                    div([class_ns("border border-right")], []),
-                ),
-                view_if(
-                    self.options.has_borders,
-                    div([class_ns("border border-top")], []),
-                ),
-                view_if(
-                    self.options.has_borders,
-                    div([class_ns("border border-bottom")], []),
-                ),
-                // corners
-                view_if(
-                    self.options.has_corners,
-                    div([class_ns("corner corner__top-left")], []),
-                ),
-                view_if(
-                    self.options.has_corners,
-                    div([class_ns("corner corner__bottom-left")], []),
-                ),
-                view_if(
-                    self.options.has_corners,
-                    div([class_ns("corner corner__top-right")], []),
-                ),
-                view_if(
-                    self.options.has_corners,
-                    div([class_ns("corner corner__bottom-right")], []),
-                ),
-                if self.options.chipped {
+        DecoratedContainer::new(COMPONENT_NAME)
+            .has_borders(self.options.has_borders)
+            .has_corners(self.options.has_corners)
+            .has_hover(self.options.has_hover)
+            .expand_corners(self.options.expand_corners)
+            .hovered(self.hover)
+            .chipped(self.options.chipped)
+            .view(
+                [
+                    classes_ns_flag([
+                        ("clicked", self.click),
+                        ("click_highlights", self.options.click_highlights),
+                        ("skewed", self.options.skewed),
+                        // setting this will also disable the div, therefore will not activate the
+                        // events on it
+                        ("disabled", self.options.disabled),
+                        ("hidden", self.options.hidden),
+                        ("icon_only", self.icon_only),
+                    ]),
+                    if let Some(ref pallete) = self.options.pallete {
+                        class_ns(pallete.class_name())
+                    } else {
+                        empty_attr()
+                    },
+                    // normally click should be attached to the actual button element
+                    on_click(Msg::Click),
+                    // the mouseover events are attached here since the hover element z-index is
+                    // higher than the actual button, which will cause a janky animation
+                    // when the mouse is triggering alt hover in and out, since covered by the hover
+                    // layer effect
+                    on_mouseover(|_| Msg::HoverIn),
+                    on_mouseout(|_| Msg::HoverOut),
+                    on_mount(Msg::Mounted),
+                ],
+                [if self.options.chipped {
                     self.view_chipped_button()
                 } else {
                     div(
@@ -346,10 +401,16 @@ where
                         [
                             div(
                                 [class_ns("button_wrap")],
-                                [self.view_actual_button(
-                                    self.width,
-                                    self.height,
-                                )],
+                                [if self.icon_only {
+                                    // the icon only button is a square
+                                    let side = self.height.or(self.width);
+                                    self.view_actual_button(side, side)
+                                } else {
+                                    self.view_actual_button(
+                                        self.width,
+                                        self.height,
+                                    )
+                                }],
                             ),
                             div(
                                 [
@@ -360,9 +421,8 @@ where
                             ),
                         ],
                     )
-                },
-            ],
-        )
+                }],
+            )
     }
 }
 
@@ -429,17 +489,60 @@ where
         self
     }
 
+    /// show this icon before the label, such as an `Icon` from the icon set
+    pub fn with_leading_icon(mut self, icon: Node<Msg>) -> Self {
+        self.leading_icon = Some(icon);
+        self
+    }
+
+    /// show this icon after the label
+    pub fn with_trailing_icon(mut self, icon: Node<Msg>) -> Self {
+        self.trailing_icon = Some(icon);
+        self
+    }
+
+    /// a square button showing only the icon,
+    /// the label is not shown but is used as the accessible name of the button
+    pub fn icon_only(label: &str, icon: Node<Msg>) -> Self {
+        let mut button = Self::with_label(label).with_leading_icon(icon);
+        button.icon_only = true;
+        button
+    }
+
+    /// measure the chipped button, which is sized to its label
+    /// when the width or height is not configured
+    fn measure_chipped_button(&mut self, root: &web_sys::Element) {
+        let selector = format!(".{}__chipped_button", COMPONENT_NAME);
+        if let Ok(Some(button)) = root.query_selector(&selector) {
+            let width = button.client_width();
+            let height = button.client_height();
+            if width > 0 && height > 0 {
+                self.measured_size = Some((width as usize, height as usize));
+            }
+        }
+    }
+
+    /// a chipped button with these corners cut, instead of only the bottom right
+    pub fn chipped_with(mut self, shape: ChippedShape) -> Self {
+        self.chipped_shape = shape;
+        self.chipped()
+    }
+
     pub fn style(theme: &crate::Theme) -> String {
         let base = &theme.controls;
         let transition_time_ms = 250; //transition time for most effects on the button
-        let hover_transition_time = 100; // the transition of the lower highligh of the button when hovering
         let highlight_transition = 50; // the transition time for the highlight color of the button when clicked
-        let corner_width = 2; // width of the corner clip of this button
-        let corner_length = 8; // lengths of the corner clip of this button
-        let corner_expand_distance = 6; // distance that clips at the corner expands when the button is hovered
-        let border_width = 1; // the width of the border for each side of the button
+        let decorations = Dimension {
+            border_width: 1,
+            corner_width: 2,
+            corner_length: 8,
+            corner_expand_distance: 6,
+            corner_shadow: false,
+            has_hover: true,
+            has_pallete: true,
+        };
 
-        jss_ns! {COMPONENT_NAME,
+        let button_style = jss_ns! {COMPONENT_NAME,
 
             // the ROOT component style
             ".": {
@@ -453,174 +556,6 @@ where
                 visibility: "hidden",
             },
 
-            // HOVER at the lower  part of the button
-            ".hover": {
-                border_color: base.hover_color.clone(),
-                box_shadow: format!("{} {}", px([0,-2, 4]), base.hover_shadow.clone()),
-                z_index: 4,
-                opacity: 1,
-                position: "absolute",
-                transition: format!("width {}ms ease-in", hover_transition_time),
-                border_style: "solid",
-            },
-
-            ".has_hover.hovered .hover": {
-                width: percent(96),
-            },
-
-            ".has_hover.hovered.chipped .hover": {
-                width: percent(80),
-                transform: format!("skewX({}deg) translate({}, {})", -45, percent(-57), 0),
-                transform_origin: "bottom left",
-            },
-
-            ".hover-bottom": {
-                width: 0,
-                left: percent(50),
-                bottom: px(2),
-                transform: format!("translate({}, {})",percent(-50), 0),
-                border_width: px([4, 0, 0, 0]),
-            },
-
-            ".error .hover": {
-                border_color: theme.pallete.error.to_css(),
-                box_shadow: format!("{} {}",px([0, -2, 4]), theme.pallete.error.to_css()),
-            },
-
-            ".success .hover": {
-                border_color: theme.pallete.success.to_css(),
-                box_shadow: format!("{} {}",px([0, -2, 4]), theme.pallete.success.to_css()),
-            },
-
-            ".info .hover": {
-                border_color: theme.pallete.info.to_css(),
-                box_shadow: format!("{} {}",px([0, -2, 4]), theme.pallete.info.to_css()),
-            },
-
-            ".warning .hover": {
-                border_color: theme.pallete.warning.to_css(),
-                box_shadow: format!("{} {}",px([0, -2, 4]), theme.pallete.warning.to_css()),
-            },
-
-
-            // BORDERS these are styled divs wrapping the buttons
-            ".border": {
-                border_color: base.border_color.clone(),
-                box_shadow: format!("{} {}",px([0,0,4]), base.border_shadow.clone()),
-                z_index: 1,
-                opacity: 1,
-                position: "absolute",
-                transition: format!("all {}ms ease-in",transition_time_ms),
-                border_style: "solid",
-            },
-
-            ".error .border": {
-                border_color: theme.pallete.error.to_css(),
-                box_shadow: format!("{} {}",px([0, 0, 4]), theme.pallete.error.to_css()),
-            },
-
-            ".success .border": {
-                border_color: theme.pallete.success.to_css(),
-                box_shadow: format!("{} {}",px([0, 0, 4]), theme.pallete.success.to_css()),
-            },
-
-            ".info .border": {
-                border_color: theme.pallete.info.to_css(),
-                box_shadow: format!("{} {}",px([0, 0, 4]), theme.pallete.info.to_css()),
-            },
-
-            ".warning .border": {
-                border_color: theme.pallete.warning.to_css(),
-                box_shadow: format!("{} {}",px([0, 0, 4]), theme.pallete.warning.to_css()),
-            },
-
-
-            ".border-left": {
-                top: percent(50),
-                left: 0,
-                height: percent(100),
-                transform: format!("translate({}, {})", 0, percent(-50)),
-                border_width: px([0, 0, 0, border_width]),
-            },
-
-            ".border-right": {
-                top: percent(50),
-                right: 0,
-                height: percent(100),
-                transform: format!("translate({}, {})", 0, percent(-50)),
-                border_width: px([0, 0, 0, border_width]),
-            },
-
-            ".border-top": {
-                top: 0,
-                left: percent(50),
-                width: percent(100),
-                transform: format!("translate({}, {})", percent(-50), 0),
-                border_width: px([border_width, 0, 0, 0]),
-            },
-
-            ".border-bottom": {
-                left: percent(50),
-                width: percent(100),
-                bottom: 0,
-                transform: format!("translate({}, {})", percent(-50), 0),
-                border_width: px([border_width, 0, 0, 0]),
-            },
-
-            // CORNERS - the fancy divs which clips the button
-            ".corner": {
-                width: px(corner_length),
-                height: px(corner_length),
-                border_color: base.corner_color.clone(),
-                //box_shadow: format!("{} {}",px([0, 0, 4]), base.corner_shadow.clone()),
-                z_index: 2,
-                opacity: 1,
-                position: "absolute",
-                transition: format!("all {}ms ease-in",transition_time_ms),
-                border_style: "solid",
-            },
-
-            ".error .corner": {
-                border_color: theme.pallete.error.to_css(),
-            },
-
-            ".success .corner": {
-                border_color: theme.pallete.success.to_css(),
-            },
-
-            ".info .corner": {
-                border_color: theme.pallete.info.to_css(),
-            },
-
-            ".warning .corner": {
-                border_color: theme.pallete.warning.to_css(),
-            },
-
-
-            ".corner__top-left": {
-                left: px(-corner_width),
-                top: px(-corner_width),
-                border_width: px([corner_width, 0, 0, corner_width]),
-            },
-
-            ".corner__bottom-left": {
-                left: px(-corner_width),
-                bottom: px(-corner_width),
-                border_width: px([0, 0, corner_width, corner_width]),
-            },
-
-            ".corner__top-right": {
-                right: px(-corner_width),
-                top: px(-corner_width),
-                border_width: px([corner_width, corner_width, 0, 0]),
-            },
-
-            ".corner__bottom-right": {
-                right: px(-corner_width),
-                bottom: px(-corner_width),
-                border_width: px([0, corner_width, corner_width, 0]),
-            },
-
             ".button_wrap": {
                 background_color: base.content_background_color.clone(),
                 z_index: 3,
@@ -649,37 +584,100 @@ where
                 vertical_align: "middle",
             },
 
+            // the icons are stroked with the text color of the button
+            ".icon": {
+                display: "inline-block",
+                vertical_align: "middle",
+                line_height: 0,
+            },
+
+            ".icon path": {
+                stroke: base.button_text_color.clone(),
+            },
+
+            ".icon-leading": {
+                margin_right: px(8),
+            },
+
+            ".icon-trailing": {
+                margin_left: px(8),
+            },
+
+            ".label": {
+                vertical_align: "middle",
+            },
+
+            ".error .icon path": {
+                stroke: theme.error_color(),
+            },
+
+            ".success .icon path": {
+                stroke: theme.success_color(),
+            },
+
+            ".info .icon path": {
+                stroke: theme.info_color(),
+            },
+
+            ".warning .icon path": {
+                stroke: theme.warning_color(),
+            },
+
+            // the icon only button is a square which fits the icon
+            ".icon_only .button": {
+                width: px(ICON_ONLY_BUTTON_SIZE),
+                height: px(ICON_ONLY_BUTTON_SIZE),
+                padding: 0,
+            },
+
+            ".icon_only .chipped_button": {
+                width: px(ICON_ONLY_BUTTON_SIZE),
+                height: px(ICON_ONLY_BUTTON_SIZE),
+                padding: 0,
+            },
+
+            ".icon_only .icon-leading": {
+                margin_right: 0,
+            },
+
             ".error .button": {
-                border_color: theme.pallete.error.to_css(),
+                border_color: theme.error_color(),
             },
 
             ".success .button": {
-                border_color: theme.pallete.success.to_css(),
+                border_color: theme.success_color(),
             },
 
             ".info .button": {
-                border_color: theme.pallete.info.to_css(),
+                border_color: theme.info_color(),
             },
 
             ".warning .button": {
-                border_color: theme.pallete.warning.to_css(),
+                border_color: theme.warning_color(),
             },
 
+            // the size of the chipped wrapper and svg is set in the view,
+            // so the shape scales to the width and height of the button
             ".chipped_wrapper": {
                 position: "relative",
-                width: px(DEFAULT_CHIPPED_BUTTON_WIDTH),
-                height: px(DEFAULT_CHIPPED_BUTTON_HEIGHT),
+                display: "inline-block",
             },
 
-            // the svg of the chipped button
+            // the svg of the chipped button, stretched to the wrapper
             ".chipped_svg": {
-                width: px(DEFAULT_CHIPPED_BUTTON_WIDTH),
-                height: px(DEFAULT_CHIPPED_BUTTON_HEIGHT),
                 position: "absolute",
+                top: 0,
+                left: 0,
+                width: percent(100),
+                height: percent(100),
             },
 
             ".chipped_wrapper .chipped_button": {
-                position: "absolute",
+                position: "relative",
+                display: "block",
+                box_sizing: "border-box",
+                padding: px([10, 30]),
+                white_space: "nowrap",
                 background_color: "transparent",
                 border: 0,
             },
@@ -699,40 +697,40 @@ where
             },
 
             ".error .chipped_polygon": {
-                stroke: theme.pallete.error.to_css(),
+                stroke: theme.error_color(),
             },
 
             ".success .chipped_polygon": {
-                stroke: theme.pallete.success.to_css(),
+                stroke: theme.success_color(),
             },
 
             ".info .chipped_polygon": {
-                stroke: theme.pallete.info.to_css(),
+                stroke: theme.info_color(),
             },
 
             ".warning .chipped_polygon": {
-                stroke: theme.pallete.warning.to_css(),
+                stroke: theme.warning_color(),
             },
 
 
             ".error .triangle": {
-                fill: theme.pallete.error.to_css(),
-                stroke: theme.pallete.error.to_css(),
+                fill: theme.error_color(),
+                stroke: theme.error_color(),
             },
 
             ".success .triangle": {
-                fill: theme.pallete.success.to_css(),
-                stroke: theme.pallete.success.to_css(),
+                fill: theme.success_color(),
+                stroke: theme.success_color(),
             },
 
             ".info .triangle": {
-                fill: theme.pallete.info.to_css(),
-                stroke: theme.pallete.info.to_css(),
+                fill: theme.info_color(),
+                stroke: theme.info_color(),
             },
 
             ".warning .triangle": {
-                fill: theme.pallete.warning.to_css(),
-                stroke: theme.pallete.warning.to_css(),
+                fill: theme.warning_color(),
+                stroke: theme.warning_color(),
             },
 
 
@@ -754,19 +752,19 @@ where
             },
 
             ".click_highlights.clicked.error .highlight": {
-                background_color: theme.pallete.error.to_css(),
+                background_color: theme.error_color(),
             },
 
             ".click_highlights.clicked.success .highlight": {
-                background_color: theme.pallete.success.to_css(),
+                background_color: theme.success_color(),
             },
 
             ".click_highlights.clicked.info .highlight": {
-                background_color: theme.pallete.info.to_css(),
+                background_color: theme.info_color(),
             },
 
             ".click_highlights.clicked.warning .highlight": {
-                background_color: theme.pallete.warning.to_css(),
+                background_color: theme.warning_color(),
             },
 
             ".click_highlights.clicked .chipped_polygon": {
@@ -774,19 +772,19 @@ where
             },
 
             ".click_highlights.clicked.error .chipped_polygon": {
-                fill: theme.pallete.error.to_css(),
+                fill: theme.error_color(),
             },
 
             ".click_highlights.clicked.success .chipped_polygon": {
-                fill: theme.pallete.success.to_css(),
+                fill: theme.success_color(),
             },
 
             ".click_highlights.clicked.info .chipped_polygon": {
-                fill: theme.pallete.info.to_css(),
+                fill: theme.info_color(),
             },
 
             ".click_highlights.clicked.warning .chipped_polygon": {
-                fill: theme.pallete.warning.to_css(),
+                fill: theme.warning_color(),
             },
 
             ".skewed": {
@@ -798,37 +796,13 @@ where
             ".skewed .button": {
                 transform: format!("skewX({}deg)", 45),
             },
+        };
 
-            // if expand_corners is enabled
-            // the fui_button corners will EXPAND when hovered.
-            //
-            // CSS Notes:
-            // - `.class1.class2 child` means if both class1 and class2 is specified in the
-            // parent, the properties will be applied to this child element
-            //
-            //  - `.class1,.class2 child` means either if either class1 or class2 is specified in the
-            // parent, the properties will be applied to this child element
-            //
-            ".expand_corners.hovered .corner__top-left": {
-                left: px(-corner_expand_distance),
-                top: px(-corner_expand_distance),
-            },
-
-            ".expand_corners.hovered .corner__bottom-left": {
-                left: px(-corner_expand_distance),
-                bottom: px(-corner_expand_distance),
-            },
-
-            ".expand_corners.hovered .corner__top-right": {
-                right: px(-corner_expand_distance),
-                top: px(-corner_expand_distance),
-            },
-
-            ".expand_corners.hovered .corner__bottom-right": {
-                right: px(-corner_expand_distance),
-                bottom: px(-corner_expand_distance),
-            },
-        }
+        format!(
+            "{}{}",
+            decorations.style(COMPONENT_NAME, theme),
+            button_style
+        )
     }
 }
 
@@ -944,3 +918,6 @@ impl Options {
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
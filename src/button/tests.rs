@@ -0,0 +1,72 @@
+use super::*;
+
+#[test]
+fn chipped_size_defaults_before_mounted() {
+    let button = Button::<()>::with_label("Deploy").chipped();
+    assert_eq!(
+        button.chipped_size(),
+        (DEFAULT_CHIPPED_BUTTON_WIDTH, DEFAULT_CHIPPED_BUTTON_HEIGHT)
+    );
+}
+
+#[test]
+fn chipped_size_uses_the_measured_label() {
+    let mut button = Button::<()>::with_label("Deploy").chipped();
+    button.measured_size = Some((96, 38));
+    assert_eq!(button.chipped_size(), (96, 38));
+}
+
+#[test]
+fn configured_size_takes_precedence_over_measured() {
+    let mut button = Button::<()>::with_label("Deploy").chipped().width(300);
+    button.measured_size = Some((96, 38));
+    assert_eq!(button.chipped_size(), (300, 38));
+
+    let button = button.height(60);
+    assert_eq!(button.chipped_size(), (300, 60));
+}
+
+#[test]
+fn chipped_polygon_scales_to_the_configured_size() {
+    let button = Button::<()>::with_label("Deploy")
+        .chipped()
+        .width(300)
+        .height(60);
+    let html = crate::ssr::render_to_string(&button.view());
+    assert!(html.contains("viewBox=\"0 0 300 60\""));
+    assert!(html.contains("points=\"0,0 300,0 300,40 280,60 0,60\""));
+    assert!(html.contains("style=\"width:300px; height:60px;\""));
+}
+
+#[test]
+fn icon_only_button_is_labelled_for_assistive_technologies() {
+    let button = Button::<()>::icon_only("Close", crate::Icon::Close.view());
+    let html = crate::ssr::render_to_string(&button.view());
+    assert!(html.contains("aria-label=\"Close\""));
+    assert!(html.contains("fui-button__icon_only"));
+    assert!(!html.contains("fui-button__label"));
+}
+
+#[test]
+fn icon_only_button_is_square() {
+    let button =
+        Button::<()>::icon_only("Play", crate::Icon::Play.view()).height(48);
+    let html = crate::ssr::render_to_string(&button.view());
+    assert!(html.contains("style=\"width:48px; height:48px;\""));
+}
+
+#[test]
+fn click_sound_follows_the_pallete() {
+    assert_eq!(
+        Button::<()>::with_label("Launch").click_sound(),
+        SoundEvent::Click
+    );
+    assert_eq!(
+        Button::<()>::with_label("Abort").error().click_sound(),
+        SoundEvent::Error
+    );
+    assert_eq!(
+        Button::<()>::with_label("Done").success().click_sound(),
+        SoundEvent::Success
+    );
+}
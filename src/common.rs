@@ -1,9 +1,9 @@
-use crate::Theme;
+use crate::{icon::Icon, Theme};
 use sauron::jss;
 use sauron::prelude::*;
 use sauron::units::px;
 
-pub(crate) fn style(theme: &Theme) -> String {
+pub fn style(theme: &Theme) -> String {
     jss! (
         "blockquote": {
             border_left: format!("{} solid {}",px(6.0), &theme.controls.border_color),
@@ -24,11 +24,24 @@ pub(crate) fn style(theme: &Theme) -> String {
         },
 
         "ul li": {
-            list_style_image: format!("url('data:image/svg+xml;base64,{}')", base64::encode(bullet_icon(theme).render_to_string())),
+            list_style_image: if theme.uses_css_variables() {
+                format!("var({}bullet-icon)", crate::theme::VARIABLE_PREFIX)
+            } else {
+                bullet_icon_url(theme)
+            },
         }
     )
 }
 
+/// the bullet icon as a data url, the icon is an image which can not
+/// resolve css variables, so it is declared as a variable itself.
+pub(crate) fn bullet_icon_url(theme: &Theme) -> String {
+    format!(
+        "url('data:image/svg+xml;base64,{}')",
+        base64::encode(bullet_icon(theme).render_to_string())
+    )
+}
+
 fn bullet_icon(theme: &Theme) -> Node<()> {
     node! {
         <svg height="24" width="24" xmlns="http://www.w3.org/2000/svg">
@@ -48,7 +61,7 @@ fn bullet_icon(theme: &Theme) -> Node<()> {
                 )
             }
              </style>
-            <path d="M4 8 l4 0 l3 4 l-3 4 l-4 0 z"/>
+            <path d={Icon::Bullet.path()}/>
         </svg>
     }
 }
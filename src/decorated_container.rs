@@ -0,0 +1,420 @@
+//! The borders, corners and hover effect shared by the widgets.
+//!
+//! The decorations are namespaced with the component which uses them,
+//! so a `Button` has `fui-button__corner` while a `Frame` has `fui-frame__corner`.
+//! The root element of the container has these class flags which the style depends on:
+//! `expand_corners`, `has_hover`, `hovered` and `chipped`, and optionally one of the
+//! pallete classes: `error`, `success`, `info` or `warning`.
+use sauron::jss_ns;
+use sauron::{
+    html::attributes,
+    html::{attributes::class, div},
+    prelude::*,
+    Attribute, Node,
+};
+
+/// the corners of the container
+const CORNERS: [&str; 4] = [
+    "corner__top-left",
+    "corner__bottom-left",
+    "corner__top-right",
+    "corner__bottom-right",
+];
+
+/// the borders of the container
+const BORDERS: [&str; 4] =
+    ["border-left", "border-right", "border-top", "border-bottom"];
+
+/// A container which wraps its content with borders at the 4 sides,
+/// corners that clips the 4 edges and a hover highlight at the bottom.
+pub struct DecoratedContainer<MSG> {
+    namespace: &'static str,
+    has_borders: bool,
+    has_corners: bool,
+    has_hover: bool,
+    expand_corners: bool,
+    hovered: bool,
+    chipped: bool,
+    /// additional attributes of each of the corners
+    corner_attributes: Vec<Attribute<MSG>>,
+}
+
+/// The sizes of the borders and corners in px
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dimension {
+    /// the width of the border for each side
+    pub border_width: i32,
+    /// the width of the corner clips
+    pub corner_width: i32,
+    /// the lengths of the corner clips
+    pub corner_length: i32,
+    /// the distance that the corners expands when hovered
+    pub corner_expand_distance: i32,
+    /// the corners glows with the corner shadow of the theme
+    pub corner_shadow: bool,
+    /// include the style of the hover highlight at the bottom
+    pub has_hover: bool,
+    /// include the colors of the `error`, `success`, `info` and `warning` classes
+    pub has_pallete: bool,
+}
+
+impl<MSG> DecoratedContainer<MSG> {
+    /// a container with borders and corners, using the namespace of the component
+    pub fn new(namespace: &'static str) -> Self {
+        DecoratedContainer {
+            namespace,
+            has_borders: true,
+            has_corners: true,
+            has_hover: false,
+            expand_corners: false,
+            hovered: false,
+            chipped: false,
+            corner_attributes: vec![],
+        }
+    }
+
+    pub fn has_borders(mut self, has_borders: bool) -> Self {
+        self.has_borders = has_borders;
+        self
+    }
+
+    pub fn has_corners(mut self, has_corners: bool) -> Self {
+        self.has_corners = has_corners;
+        self
+    }
+
+    /// show a highlight at the bottom when hovered
+    pub fn has_hover(mut self, has_hover: bool) -> Self {
+        self.has_hover = has_hover;
+        self
+    }
+
+    /// expand the corners when hovered
+    pub fn expand_corners(mut self, expand_corners: bool) -> Self {
+        self.expand_corners = expand_corners;
+        self
+    }
+
+    /// the container is currently hovered
+    pub fn hovered(mut self, hovered: bool) -> Self {
+        self.hovered = hovered;
+        self
+    }
+
+    /// the bottom right of the container is chipped
+    pub fn chipped(mut self, chipped: bool) -> Self {
+        self.chipped = chipped;
+        self
+    }
+
+    /// add these attributes to each of the corners
+    pub fn corner_attributes(
+        mut self,
+        attributes: impl IntoIterator<Item = Attribute<MSG>>,
+    ) -> Self {
+        self.corner_attributes.extend(attributes);
+        self
+    }
+}
+
+impl<MSG> DecoratedContainer<MSG>
+where
+    MSG: 'static,
+{
+    /// wrap the content in the decorations,
+    /// the attributes are added to the root element of the container.
+    pub fn view(
+        self,
+        attrs: impl IntoIterator<Item = Attribute<MSG>>,
+        content: impl IntoIterator<Item = Node<MSG>>,
+    ) -> Node<MSG> {
+        let namespace = self.namespace;
+        let class_ns =
+            |class_names| attributes::class_namespaced(namespace, class_names);
+        let classes_ns_flag = |class_name_flags| {
+            attributes::classes_flag_namespaced(namespace, class_name_flags)
+        };
+
+        let mut root_attrs = vec![class(namespace)];
+        root_attrs.extend(attrs);
+        root_attrs.push(classes_ns_flag([
+            ("expand_corners", self.expand_corners),
+            ("has_hover", self.has_hover),
+            ("hovered", self.hovered),
+            ("chipped", self.chipped),
+        ]));
+
+        let mut children = vec![view_if(
+            self.has_hover,
+            div([class_ns("hover hover-bottom")], []),
+        )];
+        children.extend(BORDERS.iter().map(|border| {
+            view_if(
+                self.has_borders,
+                div(
+                    [attributes::class_namespaced(
+                        namespace,
+                        format!("border {}", border),
+                    )],
+                    [],
+                ),
+            )
+        }));
+        children.extend(CORNERS.iter().map(|corner| {
+            let mut corner_attrs = vec![attributes::class_namespaced(
+                namespace,
+                format!("corner {}", corner),
+            )];
+            corner_attrs.extend(self.corner_attributes.iter().cloned());
+            view_if(self.has_corners, div(corner_attrs, []))
+        }));
+        children.extend(content);
+
+        div(root_attrs, children)
+    }
+}
+
+impl Dimension {
+    /// the style of the decorations namespaced with the component
+    pub fn style(&self, namespace: &str, theme: &crate::Theme) -> String {
+        let base = &theme.controls;
+        let transition_time_ms = 250; //transition time for most effects on the decorations
+        let hover_transition_time = 100; // the transition of the lower highligh when hovering
+        let border_width = self.border_width;
+        let corner_width = self.corner_width;
+        let corner_length = self.corner_length;
+        let corner_expand_distance = self.corner_expand_distance;
+        let corner_shadow = if self.corner_shadow {
+            format!("{} {}", px([0, 0, 4]), base.corner_shadow)
+        } else {
+            "none".to_string()
+        };
+
+        let hover = jss_ns! {namespace,
+            // HOVER at the lower  part of the container
+            ".hover": {
+                border_color: base.hover_color.clone(),
+                box_shadow: format!("{} {}", px([0,-2, 4]), base.hover_shadow.clone()),
+                z_index: 4,
+                opacity: 1,
+                position: "absolute",
+                transition: format!("width {}ms ease-in", hover_transition_time),
+                border_style: "solid",
+            },
+
+            ".has_hover.hovered .hover": {
+                width: percent(96),
+            },
+
+            ".has_hover.hovered.chipped .hover": {
+                width: percent(80),
+                transform: format!("skewX({}deg) translate({}, {})", -45, percent(-57), 0),
+                transform_origin: "bottom left",
+            },
+
+            ".hover-bottom": {
+                width: 0,
+                left: percent(50),
+                bottom: px(2),
+                transform: format!("translate({}, {})",percent(-50), 0),
+                border_width: px([4, 0, 0, 0]),
+            },
+        };
+
+        let hover_pallete = jss_ns! {namespace,
+            ".error .hover": {
+                border_color: theme.error_color(),
+                box_shadow: format!("{} {}",px([0, -2, 4]), theme.error_color()),
+            },
+
+            ".success .hover": {
+                border_color: theme.success_color(),
+                box_shadow: format!("{} {}",px([0, -2, 4]), theme.success_color()),
+            },
+
+            ".info .hover": {
+                border_color: theme.info_color(),
+                box_shadow: format!("{} {}",px([0, -2, 4]), theme.info_color()),
+            },
+
+            ".warning .hover": {
+                border_color: theme.warning_color(),
+                box_shadow: format!("{} {}",px([0, -2, 4]), theme.warning_color()),
+            },
+        };
+
+        let borders = jss_ns! {namespace,
+            // BORDERS these are styled divs wrapping the content
+            ".border": {
+                border_color: base.border_color.clone(),
+                box_shadow: format!("{} {}",px([0,0,4]), base.border_shadow.clone()),
+                z_index: 1,
+                opacity: 1,
+                position: "absolute",
+                transition: format!("all {}ms ease-in",transition_time_ms),
+                border_style: "solid",
+            },
+
+            ".border-left": {
+                top: percent(50),
+                left: 0,
+                height: percent(100),
+                transform: format!("translate({}, {})", 0, percent(-50)),
+                border_width: px([0, 0, 0, border_width]),
+            },
+
+            ".border-right": {
+                top: percent(50),
+                right: 0,
+                height: percent(100),
+                transform: format!("translate({}, {})", 0, percent(-50)),
+                border_width: px([0, 0, 0, border_width]),
+            },
+
+            ".border-top": {
+                top: 0,
+                left: percent(50),
+                width: percent(100),
+                transform: format!("translate({}, {})", percent(-50), 0),
+                border_width: px([border_width, 0, 0, 0]),
+            },
+
+            ".border-bottom": {
+                left: percent(50),
+                width: percent(100),
+                bottom: 0,
+                transform: format!("translate({}, {})", percent(-50), 0),
+                border_width: px([border_width, 0, 0, 0]),
+            },
+        };
+
+        let border_pallete = jss_ns! {namespace,
+            ".error .border": {
+                border_color: theme.error_color(),
+                box_shadow: format!("{} {}",px([0, 0, 4]), theme.error_color()),
+            },
+
+            ".success .border": {
+                border_color: theme.success_color(),
+                box_shadow: format!("{} {}",px([0, 0, 4]), theme.success_color()),
+            },
+
+            ".info .border": {
+                border_color: theme.info_color(),
+                box_shadow: format!("{} {}",px([0, 0, 4]), theme.info_color()),
+            },
+
+            ".warning .border": {
+                border_color: theme.warning_color(),
+                box_shadow: format!("{} {}",px([0, 0, 4]), theme.warning_color()),
+            },
+        };
+
+        let corners = jss_ns! {namespace,
+            // CORNERS - the fancy divs which clips the content
+            ".corner": {
+                width: px(corner_length),
+                height: px(corner_length),
+                border_color: base.corner_color.clone(),
+                box_shadow: corner_shadow,
+                z_index: 2,
+                opacity: 1,
+                position: "absolute",
+                transition: format!("all {}ms ease-in",transition_time_ms),
+                border_style: "solid",
+            },
+
+            ".corner__top-left": {
+                left: px(-corner_width),
+                top: px(-corner_width),
+                border_width: px([corner_width, 0, 0, corner_width]),
+            },
+
+            ".corner__bottom-left": {
+                left: px(-corner_width),
+                bottom: px(-corner_width),
+                border_width: px([0, 0, corner_width, corner_width]),
+            },
+
+            ".corner__top-right": {
+                right: px(-corner_width),
+                top: px(-corner_width),
+                border_width: px([corner_width, corner_width, 0, 0]),
+            },
+
+            ".corner__bottom-right": {
+                right: px(-corner_width),
+                bottom: px(-corner_width),
+                border_width: px([0, corner_width, corner_width, 0]),
+            },
+
+            // if expand_corners is enabled
+            // the corners will EXPAND when hovered.
+            //
+            // CSS Notes:
+            // - `.class1.class2 child` means if both class1 and class2 is specified in the
+            // parent, the properties will be applied to this child element
+            //
+            //  - `.class1,.class2 child` means either if either class1 or class2 is specified in the
+            // parent, the properties will be applied to this child element
+            //
+            ".expand_corners.hovered .corner__top-left": {
+                left: px(-corner_expand_distance),
+                top: px(-corner_expand_distance),
+            },
+
+            ".expand_corners.hovered .corner__bottom-left": {
+                left: px(-corner_expand_distance),
+                bottom: px(-corner_expand_distance),
+            },
+
+            ".expand_corners.hovered .corner__top-right": {
+                right: px(-corner_expand_distance),
+                top: px(-corner_expand_distance),
+            },
+
+            ".expand_corners.hovered .corner__bottom-right": {
+                right: px(-corner_expand_distance),
+                bottom: px(-corner_expand_distance),
+            },
+        };
+
+        let corner_pallete = jss_ns! {namespace,
+            ".error .corner": {
+                border_color: theme.error_color(),
+            },
+
+            ".success .corner": {
+                border_color: theme.success_color(),
+            },
+
+            ".info .corner": {
+                border_color: theme.info_color(),
+            },
+
+            ".warning .corner": {
+                border_color: theme.warning_color(),
+            },
+        };
+
+        let mut css = String::new();
+        if self.has_hover {
+            css += &hover;
+            if self.has_pallete {
+                css += &hover_pallete;
+            }
+        }
+        css += &borders;
+        if self.has_pallete {
+            css += &border_pallete;
+        }
+        css += &corners;
+        if self.has_pallete {
+            css += &corner_pallete;
+        }
+        css
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+use crate::ssr::render_to_string;
+
+#[test]
+fn decorations_are_namespaced_with_the_component() {
+    let node: Node<()> =
+        DecoratedContainer::new("fui-widget").view([], [text("content")]);
+    let html = render_to_string(&node);
+    assert!(html.starts_with("<div class=\"fui-widget\""));
+    assert!(html.contains("fui-widget__border fui-widget__border-left"));
+    assert!(html.contains("fui-widget__corner fui-widget__corner__top-left"));
+    assert!(!html.contains("fui-widget__hover"));
+    assert!(html.ends_with("content</div>"));
+}
+
+#[test]
+fn borders_and_corners_can_be_left_out() {
+    let node: Node<()> = DecoratedContainer::new("fui-widget")
+        .has_borders(false)
+        .has_corners(false)
+        .has_hover(true)
+        .view([], []);
+    let html = render_to_string(&node);
+    assert!(html.contains("fui-widget__hover fui-widget__hover-bottom"));
+    assert!(!html.contains("fui-widget__border"));
+    assert!(!html.contains("fui-widget__corner"));
+}
+
+#[test]
+fn corner_attributes_are_added_to_each_corner() {
+    let node: Node<()> = DecoratedContainer::new("fui-widget")
+        .corner_attributes([attr("data-corner", "yes")])
+        .view([], []);
+    let html = render_to_string(&node);
+    assert_eq!(html.matches("data-corner=\"yes\"").count(), 4);
+}
+
+#[test]
+fn style_uses_the_dimension() {
+    let dimension = Dimension {
+        border_width: 3,
+        corner_width: 5,
+        corner_length: 20,
+        corner_expand_distance: 10,
+        corner_shadow: false,
+        has_hover: true,
+        has_pallete: true,
+    };
+    let css = dimension.style("fui-widget", &crate::Theme::default());
+    assert!(css.contains(".fui-widget__corner{width:20px;height:20px;"));
+    assert!(css.contains("box-shadow:none;"));
+    assert!(css.contains("left:-5px;top:-5px;border-width:5px 0px 0px 5px;"));
+    assert!(css.contains("left:-10px;top:-10px;"));
+}
+
+#[test]
+fn hover_and_pallete_styles_can_be_left_out() {
+    let dimension = Dimension {
+        border_width: 2,
+        corner_width: 4,
+        corner_length: 16,
+        corner_expand_distance: 12,
+        corner_shadow: true,
+        has_hover: false,
+        has_pallete: false,
+    };
+    let css = dimension.style("fui-widget", &crate::Theme::default());
+    assert!(css.contains(".fui-widget__border{"));
+    assert!(css.contains(".fui-widget__corner{"));
+    assert!(!css.contains(".fui-widget__hover{"));
+    assert!(!css.contains(".fui-widget__hover-bottom"));
+    assert!(!css.contains(".fui-widget__error"));
+    assert!(!css.contains(".fui-widget__warning"));
+}
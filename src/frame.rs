@@ -1,40 +1,159 @@
-use crate::sounds;
-use sauron::jss_ns;
-use sauron::{
-    html::attributes,
-    html::{attributes::class, div},
-    prelude::*,
-    Node,
+use crate::{
+    animation::{AnimateOut, AnimateOutListeners, AnimationStart},
+    decorated_container::{DecoratedContainer, Dimension},
+    shape::ChippedShape,
+    sound_theme::SoundEvent,
 };
-use web_sys::HtmlAudioElement;
+use sauron::jss_ns;
+use sauron::{html::attributes, html::div, prelude::*, Node};
 
 const COMPONENT_NAME: &str = "fui-frame";
+const DEFAULT_DURATION: f64 = 200.0;
+/// the time it takes the borders and corners to collapse, which is their transition time
+const COLLAPSE_DURATION: f64 = 250.0;
+
+/// the corners of the frame: the name, the horizontal and the vertical side
+const CORNERS: [(&str, &str, &str); 4] = [
+    ("top-left", "left", "top"),
+    ("bottom-left", "left", "bottom"),
+    ("top-right", "right", "top"),
+    ("bottom-right", "right", "bottom"),
+];
+
+/// The animations of the corners when the frame is animated,
+/// the corners are like a robotic hand holding the frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameAnimation {
+    /// the corners start from outward then move inwards to grip the edges of the frame
+    Grip,
+    /// the corners start from the edges of the frame then move outwards
+    Release,
+    /// the corners start from inside of the frame then grip outwards to the edges
+    Grow,
+    /// the corners start from the edges of the frame then move inwards to the inside
+    Crush,
+}
+
+impl FrameAnimation {
+    pub fn all() -> [FrameAnimation; 4] {
+        [
+            FrameAnimation::Grip,
+            FrameAnimation::Release,
+            FrameAnimation::Grow,
+            FrameAnimation::Crush,
+        ]
+    }
+
+    /// the class name of the frame when this animation is used
+    fn class_name(&self) -> &'static str {
+        match self {
+            FrameAnimation::Grip => "corner-gripping",
+            FrameAnimation::Release => "corner-releasing",
+            FrameAnimation::Grow => "corner-grow",
+            FrameAnimation::Crush => "corner-crush",
+        }
+    }
+
+    /// the keyframe step and the offset of the corners in that step,
+    /// the corners are at their usual place on the other step.
+    fn keyframe(
+        &self,
+        corner_width: i32,
+        corner_expand_distance: i32,
+    ) -> (&'static str, i32) {
+        match self {
+            FrameAnimation::Grip => ("from", -corner_expand_distance),
+            FrameAnimation::Release => ("to", -corner_expand_distance),
+            FrameAnimation::Grow => {
+                ("from", corner_expand_distance - corner_width)
+            }
+            FrameAnimation::Crush => {
+                ("to", corner_expand_distance - corner_width)
+            }
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub enum Msg<XMSG> {
     AnimateIn,
+    /// collapse the borders and corners of the frame
+    AnimateOut,
     StopAnimation,
+    AnimateOutDone,
     HoverIn,
     HoverOut,
-    NextAnimation(f64, f64),
+    NextAnimation(bool, f64, f64),
     External(XMSG),
 }
 pub struct Frame<XMSG> {
-    audio: HtmlAudioElement,
     hide: bool,
+    /// the borders and corners are collapsing while animating out,
+    /// the frame is hidden once they are collapsed
+    collapsing: bool,
     hover: bool,
     content: Node<XMSG>,
+    /// the animation of the corners, the corners just appear when none
+    animation: Option<FrameAnimation>,
+    /// the duration of the animation in ms
+    duration: f64,
+    /// expand the corners when hovered
+    expand_corners: bool,
+    animating: bool,
+    is_in: bool,
+    start: AnimationStart,
+    /// the corners of the content that are cut
+    chipped_shape: ChippedShape,
+    on_animate_out: AnimateOutListeners<XMSG>,
 }
 
 impl<XMSG> Frame<XMSG> {
     pub fn with_content(content: Node<XMSG>) -> Self {
         Frame {
-            audio: sounds::preload("sounds/deploy.mp3"),
             hide: false,
+            collapsing: false,
             hover: false,
             content,
+            animation: None,
+            duration: DEFAULT_DURATION,
+            expand_corners: true,
+            animating: false,
+            is_in: true,
+            start: AnimationStart::default(),
+            chipped_shape: ChippedShape::new(),
+            on_animate_out: AnimateOutListeners::default(),
         }
     }
+
+    /// animate the corners with this animation
+    pub fn with_animation(mut self, animation: FrameAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// the duration of the animation in ms
+    pub fn with_duration(mut self, duration: f64) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// expand the corners when the frame is hovered
+    pub fn expand_corners(mut self, expand_corners: bool) -> Self {
+        self.expand_corners = expand_corners;
+        self
+    }
+
+    /// cut these corners of the content
+    pub fn with_chipped(mut self, shape: ChippedShape) -> Self {
+        self.chipped_shape = shape;
+        self
+    }
+}
+
+impl<XMSG> AnimateOut<XMSG> for Frame<XMSG> {
+    fn animate_out_listeners(&mut self) -> &mut AnimateOutListeners<XMSG> {
+        &mut self.on_animate_out
+    }
 }
 
 impl<XMSG> Component<Msg<XMSG>, XMSG> for Frame<XMSG>
@@ -43,14 +162,19 @@ where
 {
     fn update(&mut self, msg: Msg<XMSG>) -> Effects<Msg<XMSG>, XMSG> {
         match msg {
-            Msg::AnimateIn => {
-                self.hide = true;
-                Effects::with_local(self.start_animation())
-            }
+            Msg::AnimateIn => Effects::with_local(self.start_animation(true)),
+            Msg::AnimateOut => Effects::with_local(self.start_animation(false)),
             Msg::StopAnimation => {
                 self.hide = false;
+                self.animating = false;
                 Effects::none()
             }
+            Msg::AnimateOutDone => {
+                self.animating = false;
+                self.collapsing = false;
+                self.hide = true;
+                Effects::with_external(self.on_animate_out.emit())
+            }
             Msg::HoverIn => {
                 self.hover = true;
                 Effects::none()
@@ -59,8 +183,8 @@ where
                 self.hover = false;
                 Effects::none()
             }
-            Msg::NextAnimation(start, duration) => {
-                Effects::with_local(self.next_animation(start, duration))
+            Msg::NextAnimation(is_in, start, duration) => {
+                Effects::with_local(self.next_animation(is_in, start, duration))
             }
             Msg::External(pmsg) => Effects::with_external([pmsg]),
         }
@@ -78,32 +202,46 @@ where
             )
         };
 
-        div(
-            [
-                class(COMPONENT_NAME),
-                classes_ns_flag([
-                    ("hide", self.hide),
-                    ("expand_corners", true),
-                    ("hovered", self.hover),
-                ]),
-                on_mouseover(|_| Msg::HoverIn),
-                on_mouseout(|_| Msg::HoverOut),
-            ],
-            [
-                div([class_ns("border border-left")], []),
-                div([class_ns("border border-right")], []),
-                div([class_ns("border border-top")], []),
-                div([class_ns("border border-bottom")], []),
-                div([class_ns("corner corner__top-left")], []),
-                div([class_ns("corner corner__bottom-left")], []),
-                div([class_ns("corner corner__top-right")], []),
-                div([class_ns("corner corner__bottom-right")], []),
-                div(
-                    [class_ns("content")],
+        let animating = self.animating && self.animation.is_some();
+
+        DecoratedContainer::new(COMPONENT_NAME)
+            .expand_corners(self.expand_corners)
+            .hovered(self.hover)
+            .corner_attributes(if animating {
+                vec![
+                    style! {animation_duration: format!("{}ms", self.duration)},
+                ]
+            } else {
+                vec![]
+            })
+            .view(
+                [
+                    classes_ns_flag([
+                        ("hide", self.hide),
+                        ("collapsing", self.collapsing),
+                        ("animating", animating),
+                        ("reverse", animating && !self.is_in),
+                    ]),
+                    if let Some(animation) = self.animation {
+                        class_ns(animation.class_name())
+                    } else {
+                        empty_attr()
+                    },
+                    on_mouseover(|_| Msg::HoverIn),
+                    on_mouseout(|_| Msg::HoverOut),
+                ],
+                [div(
+                    [
+                        class_ns("content"),
+                        if self.chipped_shape.is_empty() {
+                            empty_attr()
+                        } else {
+                            style! {clip_path: self.chipped_shape.clip_path()}
+                        },
+                    ],
                     [self.content.clone().map_msg(Msg::External)],
-                ),
-            ],
-        )
+                )],
+            )
     }
 }
 
@@ -112,33 +250,82 @@ impl<XMSG> Frame<XMSG> {
         self.content = content;
     }
 
-    fn start_animation(&mut self) -> Vec<Msg<XMSG>> {
-        let duration = 200.0;
-        let start = crate::dom::now();
-        sounds::play(&self.audio);
-        vec![Msg::NextAnimation(start, duration)]
+    fn start_animation(&mut self, is_in: bool) -> Vec<Msg<XMSG>> {
+        self.start_animation_at(is_in, sauron::dom::now())
+    }
+
+    /// the frame is hidden while animating in, the css transitions expands it back
+    /// when the animation stops. When animating out, the frame stays visible
+    /// while its borders and corners collapse and is hidden when done.
+    fn start_animation_at(
+        &mut self,
+        is_in: bool,
+        start: f64,
+    ) -> Vec<Msg<XMSG>> {
+        if !is_in && self.hide && !self.animating {
+            return vec![Msg::AnimateOutDone];
+        }
+        let duration = if is_in {
+            self.duration
+        } else {
+            self.duration.max(COLLAPSE_DURATION)
+        };
+        self.hide = is_in;
+        self.collapsing = !is_in;
+        self.animating = true;
+        self.is_in = is_in;
+        self.start.restart(start);
+        SoundEvent::Deploy.play();
+        vec![Msg::NextAnimation(is_in, start, duration)]
     }
 
-    fn next_animation(&mut self, start: f64, duration: f64) -> Vec<Msg<XMSG>> {
-        let timestamp = crate::dom::now();
+    fn next_animation(
+        &mut self,
+        is_in: bool,
+        start: f64,
+        duration: f64,
+    ) -> Vec<Msg<XMSG>> {
+        self.next_animation_at(is_in, start, duration, sauron::dom::now())
+    }
+
+    fn next_animation_at(
+        &mut self,
+        is_in: bool,
+        start: f64,
+        duration: f64,
+        timestamp: f64,
+    ) -> Vec<Msg<XMSG>> {
+        if self.start.is_stale(start) {
+            return vec![];
+        }
         let elapsed = timestamp - start;
         let continue_animation = elapsed < duration;
         if continue_animation {
-            vec![Msg::NextAnimation(start, duration)]
-        } else {
+            vec![Msg::NextAnimation(is_in, start, duration)]
+        } else if is_in {
             vec![Msg::StopAnimation]
+        } else {
+            vec![Msg::AnimateOutDone]
         }
     }
 
     pub fn style(theme: &crate::Theme) -> String {
         let base = &theme.controls;
-        let border_width = 2;
-        let corner_expand_distance = 12;
-        let corner_width = 4;
-        let corner_length = 16;
-        let transition_time_ms = 250; //transition time for most effects on the button
+        let decorations = Dimension {
+            border_width: 2,
+            corner_width: 4,
+            corner_length: 16,
+            corner_expand_distance: 12,
+            corner_shadow: true,
+            has_hover: false,
+            has_pallete: false,
+        };
+        let corner_width = decorations.corner_width;
+        let corner_length = decorations.corner_length;
+        let corner_expand_distance = decorations.corner_expand_distance;
+        let transition_time_ms = 250; //transition time for most effects on the frame
 
-        jss_ns! {COMPONENT_NAME,
+        let main = jss_ns! {COMPONENT_NAME,
             ".": {
                 display: "block",
                 padding: px(1),
@@ -146,17 +333,8 @@ impl<XMSG> Frame<XMSG> {
                 opacity: 1,
             },
 
-            ".border": {
-                border_color: base.border_color.clone(),
-                box_shadow: format!("{} {} {} {}", 0, 0, px(4), base.border_shadow.clone()),
-                z_index: 1,
-                opacity: 1,
-                position: "absolute",
-                transition: format!("all {}ms ease-in", transition_time_ms),
-                border_style: "solid",
-            },
-
-            ".hide": {
+            // the frame is only transparent once its corners are done animating
+            ".hide:not(.animating)": {
                 opacity: 0,
             },
 
@@ -165,81 +343,12 @@ impl<XMSG> Frame<XMSG> {
                 width: 0,
             },
 
-            ".border-left": {
-                top: percent(50),
-                left: 0,
-                height: percent(100),
-                transform: format!("translate({}, {})",0, percent(-50)),
-                border_width: format!("{}",px([0, 0, 0, border_width])),
-            },
-
-            ".border-right": {
-                top: percent(50),
-                right: 0,
-                height: percent(100),
-                transform: format!("translate({}, {})",0, percent(-50)),
-                border_width: format!("{}",px([0, 0, 0, border_width])),
-            },
-
-            ".border-top": {
-                top: 0,
-                left: percent(50),
-                width: percent(100),
-                transform: format!("translate({}, {})", percent(-50), 0),
-                border_width: format!("{}",px([border_width, 0, 0, 0])),
-            },
-
-            ".border-bottom": {
-                left: percent(50),
-                width: percent(100),
-                bottom: 0,
-                transform: format!("translate({}, {})",percent(-50), 0),
-                border_width: format!("{}", px([border_width, 0, 0, 0])),
-            },
-
-            // CORNERS - the fancy divs which clips the button
-            ".corner": {
-                width: px(corner_length),
-                height: px(corner_length),
-                border_color: base.corner_color.clone(),
-                box_shadow: format!("{} {}",px([0, 0, 4]), base.corner_shadow.clone()),
-                z_index: 2,
-                opacity: 1,
-                position: "absolute",
-                transition: format!("all {}ms ease-in",transition_time_ms),
-                border_style: "solid",
-            },
-
             ".hide .corner": {
                 width: 0,
                 height: 0,
                 opacity: 0,
             },
 
-            ".corner__top-left": {
-                left: px(-corner_width),
-                top: px(-corner_width),
-                border_width: px([corner_width, 0, 0, corner_width]),
-            },
-
-            ".corner__bottom-left": {
-                left: px(-corner_width),
-                bottom: px(-corner_width),
-                border_width: px([0, 0, corner_width, corner_width]),
-            },
-
-            ".corner__top-right": {
-                right: px(-corner_width),
-                top: px(-corner_width),
-                border_width: px([corner_width, corner_width, 0, 0]),
-            },
-
-            ".corner__bottom-right": {
-                right: px(-corner_width),
-                bottom: px(-corner_width),
-                border_width: px([0, corner_width, corner_width, 0]),
-            },
-
             ".content": {
                 background_color: base.content_background_color.clone(),
                 z_index: 3,
@@ -253,36 +362,99 @@ impl<XMSG> Frame<XMSG> {
                 background_color: "transparent",
             },
 
+            // the frame stays visible while the borders and corners collapse
+            ".collapsing .border": {
+                height: 0,
+                width: 0,
+            },
 
-            // if expand_corners is enabled
-            // the fui_button corners will EXPAND when hovered.
-            //
-            // CSS Notes:
-            // - `.class1.class2 child` means if both class1 and class2 is specified in the
-            // parent, the properties will be applied to this child element
-            //
-            //  - `.class1,.class2 child` means either if either class1 or class2 is specified in the
-            // parent, the properties will be applied to this child element
-            //
-            ".expand_corners.hovered .corner__top-left": {
-                left: px(-corner_expand_distance),
-                top: px(-corner_expand_distance),
+            ".collapsing .corner": {
+                width: 0,
+                height: 0,
+                opacity: 0,
             },
 
-            ".expand_corners.hovered .corner__bottom-left": {
-                left: px(-corner_expand_distance),
-                bottom: px(-corner_expand_distance),
+            ".collapsing .content": {
+                background_color: "transparent",
+            },
+
+            ".collapsing.animating .corner": {
+                width: px(corner_length),
+                height: px(corner_length),
+                opacity: 1,
             },
 
-            ".expand_corners.hovered .corner__top-right": {
-                right: px(-corner_expand_distance),
-                top: px(-corner_expand_distance),
+            // the corners are shown while they are animated
+            ".hide.animating .corner": {
+                width: px(corner_length),
+                height: px(corner_length),
+                opacity: 1,
             },
 
-            ".expand_corners.hovered .corner__bottom-right": {
-                right: px(-corner_expand_distance),
-                bottom: px(-corner_expand_distance),
+            ".reverse .corner": {
+                animation_direction: "reverse",
             },
-        }
+        };
+
+        let animations = FrameAnimation::all()
+            .iter()
+            .map(|animation| {
+                Self::corner_animation_style(
+                    *animation,
+                    corner_width,
+                    corner_expand_distance,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("");
+
+        format!(
+            "{}{}{}",
+            decorations.style(COMPONENT_NAME, theme),
+            main,
+            animations
+        )
+    }
+
+    /// the keyframes of each of the corners for this animation,
+    /// the duration is set in the corners while animating
+    fn corner_animation_style(
+        animation: FrameAnimation,
+        corner_width: i32,
+        corner_expand_distance: i32,
+    ) -> String {
+        let (step, offset) =
+            animation.keyframe(corner_width, corner_expand_distance);
+        CORNERS
+            .iter()
+            .map(|(corner, horizontal, vertical)| {
+                let keyframes_name = format!(
+                    "{}-{}-{}",
+                    COMPONENT_NAME,
+                    animation.class_name(),
+                    corner
+                );
+                let selector = format!(
+                    ".animating.{} .corner__{}",
+                    animation.class_name(),
+                    corner
+                );
+                let keyframes = format!("@keyframes {}", keyframes_name);
+                jss_ns! {COMPONENT_NAME,
+                    [selector.as_str()]: {
+                        animation: format!("{} {}ms ease-out both", keyframes_name, DEFAULT_DURATION),
+                    },
+                    [keyframes.as_str()]: {
+                        [step]: {
+                            [*horizontal]: px(offset),
+                            [*vertical]: px(offset),
+                        }
+                    },
+                }
+            })
+            .collect()
     }
 }
+
+#[cfg(test)]
+mod tests;
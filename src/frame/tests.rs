@@ -0,0 +1,90 @@
+use super::*;
+
+fn render(frame: &Frame<()>) -> String {
+    crate::ssr::render_to_string(&frame.view())
+}
+
+#[test]
+fn frame_stays_visible_while_collapsing() {
+    let mut frame = Frame::<()>::with_content(text("briefing"));
+    let follow_ups = frame.start_animation_at(false, 1000.0);
+    assert!(matches!(
+        follow_ups[..],
+        [Msg::NextAnimation(false, start, duration)]
+            if start == 1000.0 && duration == COLLAPSE_DURATION
+    ));
+    let html = render(&frame);
+    assert!(html.contains("fui-frame__collapsing"));
+    assert!(!html.contains("fui-frame__hide"));
+
+    frame.update(Msg::AnimateOutDone);
+    let html = render(&frame);
+    assert!(html.contains("fui-frame__hide"));
+    assert!(!html.contains("fui-frame__collapsing"));
+}
+
+#[test]
+fn collapsing_frame_is_not_transparent() {
+    let css = Frame::<()>::style(&crate::Theme::default());
+    assert!(css.contains(".fui-frame__collapsing .fui-frame__border{"));
+    assert!(!css.contains(".fui-frame__collapsing{"));
+}
+
+#[test]
+fn animating_frame_shows_its_corners() {
+    let mut frame = Frame::<()>::with_content(text("briefing"))
+        .with_animation(FrameAnimation::Grip)
+        .with_duration(400.0);
+    frame.start_animation_at(true, 0.0);
+    let html = render(&frame);
+    assert!(html.contains("fui-frame__animating"));
+    assert!(html.contains("fui-frame__corner-gripping"));
+    assert!(html.contains("animation-duration:400ms"));
+
+    let css = Frame::<()>::style(&crate::Theme::default());
+    assert!(
+        css.contains(".fui-frame__hide:not(.fui-frame__animating){opacity:0;}")
+    );
+    assert!(!css.contains(".fui-frame__hide{"));
+}
+
+#[test]
+fn frame_has_no_hover_or_pallete_style() {
+    let css = Frame::<()>::style(&crate::Theme::default());
+    assert!(!css.contains(".fui-frame__hover{"));
+    assert!(!css.contains(".fui-frame__hover-bottom"));
+    assert!(!css.contains(".fui-frame__error"));
+    assert!(!css.contains(".fui-frame__success"));
+}
+
+#[test]
+fn animating_in_while_collapsing_drops_the_animate_out() {
+    let mut frame = Frame::<()>::with_content(text("briefing"));
+    frame.start_animation_at(false, 1000.0);
+    frame.start_animation_at(true, 1030.0);
+
+    let stale =
+        frame.next_animation_at(false, 1000.0, COLLAPSE_DURATION, 1300.0);
+    assert!(stale.is_empty());
+    let follow_ups =
+        frame.next_animation_at(true, 1030.0, DEFAULT_DURATION, 1300.0);
+    assert!(matches!(follow_ups[..], [Msg::StopAnimation]));
+    frame.update(Msg::StopAnimation);
+
+    let html = render(&frame);
+    assert!(!html.contains("fui-frame__hide"));
+    assert!(!html.contains("fui-frame__collapsing"));
+}
+
+#[test]
+fn animating_out_a_hidden_frame_is_done_right_away() {
+    let mut frame = Frame::<()>::with_content(text("briefing"));
+    frame.start_animation_at(false, 1000.0);
+    frame.update(Msg::AnimateOutDone);
+
+    let follow_ups = frame.start_animation_at(false, 2000.0);
+    assert!(matches!(follow_ups[..], [Msg::AnimateOutDone]));
+    let html = render(&frame);
+    assert!(html.contains("fui-frame__hide"));
+    assert!(!html.contains("fui-frame__collapsing"));
+}
@@ -0,0 +1,157 @@
+//! A set of inline svg icons drawn in the style of the list bullet icon,
+//! a 24x24 outline with a 2px stroke.
+//!
+//! The icons are colored by the `style` of this module,
+//! so they follow the `Theme` and can be used in the `Button` icon slots.
+use crate::Theme;
+use sauron::{
+    html::attributes::{self, class},
+    jss_ns,
+    prelude::*,
+    Node,
+};
+
+const COMPONENT_NAME: &str = "fui-icon";
+
+/// the icons of the set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Icon {
+    Bullet,
+    Play,
+    Pause,
+    Stop,
+    Close,
+    Check,
+    Plus,
+    Minus,
+    ArrowLeft,
+    ArrowRight,
+    Menu,
+    Search,
+    Sound,
+    Mute,
+}
+
+impl Icon {
+    /// all the icons of the set
+    pub fn all() -> [Icon; 14] {
+        [
+            Icon::Bullet,
+            Icon::Play,
+            Icon::Pause,
+            Icon::Stop,
+            Icon::Close,
+            Icon::Check,
+            Icon::Plus,
+            Icon::Minus,
+            Icon::ArrowLeft,
+            Icon::ArrowRight,
+            Icon::Menu,
+            Icon::Search,
+            Icon::Sound,
+            Icon::Mute,
+        ]
+    }
+
+    /// the name of the icon, used in its class name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Icon::Bullet => "bullet",
+            Icon::Play => "play",
+            Icon::Pause => "pause",
+            Icon::Stop => "stop",
+            Icon::Close => "close",
+            Icon::Check => "check",
+            Icon::Plus => "plus",
+            Icon::Minus => "minus",
+            Icon::ArrowLeft => "arrow-left",
+            Icon::ArrowRight => "arrow-right",
+            Icon::Menu => "menu",
+            Icon::Search => "search",
+            Icon::Sound => "sound",
+            Icon::Mute => "mute",
+        }
+    }
+
+    /// the path data of the icon in a 24x24 view box
+    pub fn path(&self) -> &'static str {
+        match self {
+            Icon::Bullet => "M4 8 l4 0 l3 4 l-3 4 l-4 0 z",
+            Icon::Play => "M7 5 l11 7 l-11 7 z",
+            Icon::Pause => "M7 5 l3 0 l0 14 l-3 0 z M14 5 l3 0 l0 14 l-3 0 z",
+            Icon::Stop => "M6 6 l12 0 l0 12 l-12 0 z",
+            Icon::Close => "M6 6 L18 18 M18 6 L6 18",
+            Icon::Check => "M5 12 l5 5 l9 -10",
+            Icon::Plus => "M12 5 L12 19 M5 12 L19 12",
+            Icon::Minus => "M5 12 L19 12",
+            Icon::ArrowLeft => "M19 12 L5 12 M11 6 l-6 6 l6 6",
+            Icon::ArrowRight => "M5 12 L19 12 M13 6 l6 6 l-6 6",
+            Icon::Menu => "M4 6 L20 6 M4 12 L20 12 M4 18 L20 18",
+            Icon::Search => "M10 4 l6 6 l-6 6 l-6 -6 z M14 14 l6 6",
+            Icon::Sound => {
+                "M4 9 l4 0 l5 -4 l0 14 l-5 -4 l-4 0 z M16 9 l2 3 l-2 3"
+            }
+            Icon::Mute => {
+                "M4 9 l4 0 l5 -4 l0 14 l-5 -4 l-4 0 z M16 9 l5 6 M21 9 l-5 6"
+            }
+        }
+    }
+
+    /// the closed shapes are filled with the primary color, the rest are only stroked
+    pub fn is_filled(&self) -> bool {
+        matches!(self, Icon::Bullet | Icon::Play | Icon::Pause | Icon::Stop)
+    }
+
+    /// the icon as an inline svg, it is hidden from assistive technologies
+    /// since the label of the control describes it
+    pub fn view<MSG>(&self) -> Node<MSG> {
+        let class_ns = |class_names| {
+            attributes::class_namespaced(COMPONENT_NAME, class_names)
+        };
+        svg(
+            [
+                class(COMPONENT_NAME),
+                class_ns(self.name()),
+                attributes::classes_flag_namespaced(
+                    COMPONENT_NAME,
+                    [("filled", self.is_filled())],
+                ),
+                xmlns("http://www.w3.org/2000/svg"),
+                viewBox([0, 0, 24, 24]),
+                width(24),
+                height(24),
+                attr("aria-hidden", "true"),
+                attr("focusable", "false"),
+            ],
+            [path([class_ns("path"), d(self.path())], [])],
+        )
+    }
+}
+
+/// the colors of the icons, the stroke follows the border color
+/// while the filled icons use the primary color like the list bullets
+pub fn style(theme: &Theme) -> String {
+    jss_ns! {COMPONENT_NAME,
+        ".": {
+            display: "inline-block",
+            vertical_align: "middle",
+            overflow: "visible",
+        },
+
+        ".path": {
+            stroke: theme.controls.border_color.clone(),
+            stroke_width: 2,
+            stroke_opacity: 1,
+            fill: "none",
+            stroke_linecap: "round",
+            stroke_linejoin: "miter",
+        },
+
+        ".filled .path": {
+            fill: theme.primary_color.clone(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,29 @@
+use super::*;
+use crate::ssr::render_to_string;
+
+#[test]
+fn icons_have_unique_names() {
+    let mut names: Vec<_> =
+        Icon::all().iter().map(|icon| icon.name()).collect();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), Icon::all().len());
+}
+
+#[test]
+fn icon_is_an_inline_svg_hidden_from_assistive_technologies() {
+    let node: Node<()> = Icon::Play.view();
+    let html = render_to_string(&node);
+    assert!(html.starts_with(
+        "<svg class=\"fui-icon fui-icon__play fui-icon__filled\""
+    ));
+    assert!(html.contains("viewBox=\"0 0 24 24\""));
+    assert!(html.contains("aria-hidden=\"true\""));
+    assert!(html.contains(Icon::Play.path()));
+}
+
+#[test]
+fn outline_icons_are_not_filled() {
+    let html = render_to_string(&Icon::Close.view::<()>());
+    assert!(!html.contains("fui-icon__filled"));
+}
@@ -1,20 +1,27 @@
-use crate::{frame, sounds, Frame};
+use crate::{
+    animation::{
+        AnimateOut, AnimateOutListeners, AnimationStart, TypingTicker,
+    },
+    frame, AnimationOptions, ChippedShape, Frame,
+};
 use sauron::{
     html::{attributes, div},
     jss_ns,
     prelude::*,
     Node,
 };
-use web_sys::HtmlAudioElement;
 
 const COMPONENT_NAME: &str = "fui-image";
 
 #[derive(Clone, Debug)]
 pub enum Msg {
     AnimateIn,
+    /// hide the image slice by slice in reverse
+    AnimateOut,
     FrameMsg(Box<frame::Msg<Msg>>),
     AnimationDone,
     StopAnimation,
+    AnimateOutDone,
     NextAnimation(bool, f64, f64),
 }
 
@@ -27,14 +34,19 @@ pub struct Properties {
     gap: f32,
 }
 
-pub struct Image {
-    audio: HtmlAudioElement,
+pub struct Image<XMSG> {
     frame: Frame<Msg>,
     properties: Properties,
+    options: AnimationOptions,
+    typing_ticker: TypingTicker,
     is_animating: bool,
+    start: AnimationStart,
+    /// the image is hidden after animating out
+    hidden: bool,
+    on_animate_out: AnimateOutListeners<XMSG>,
 }
 
-impl Image {
+impl<XMSG> Image<XMSG> {
     pub fn new(url: impl ToString) -> Self {
         let width = 1000.0;
         let height = 600.0;
@@ -50,30 +62,64 @@ impl Image {
         };
 
         Image {
-            audio: sounds::preload("sounds/typing.mp3"),
             frame: Frame::with_content(properties.slice_view(None)),
             properties,
+            options: AnimationOptions::default(),
+            typing_ticker: TypingTicker::default(),
             is_animating: false,
+            start: AnimationStart::default(),
+            hidden: false,
+            on_animate_out: AnimateOutListeners::default(),
         }
     }
 
+    /// use these options for the timing of the slice animation
+    pub fn with_animation_options(mut self, options: AnimationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// cut these corners of the image frame
+    pub fn with_chipped(mut self, shape: ChippedShape) -> Self {
+        self.frame = self.frame.with_chipped(shape);
+        self
+    }
+
     pub fn style(&self, theme: &crate::Theme) -> String {
         self.properties.style(theme)
     }
 }
 
+impl<XMSG> AnimateOut<XMSG> for Image<XMSG> {
+    fn animate_out_listeners(&mut self) -> &mut AnimateOutListeners<XMSG> {
+        &mut self.on_animate_out
+    }
+}
+
 ///TODO: create a copy of AnimateList effects here, so we can customize it specific to the image.
 /// Wrap this with frame as well
-impl Component<Msg, ()> for Image {
-    fn update(&mut self, msg: Msg) -> Effects<Msg, ()> {
+impl<XMSG> Component<Msg, XMSG> for Image<XMSG>
+where
+    XMSG: 'static,
+{
+    fn update(&mut self, msg: Msg) -> Effects<Msg, XMSG> {
         match msg {
             Msg::AnimateIn => {
                 self.is_animating = true;
                 Effects::with_local(self.animate_in())
             }
+            Msg::AnimateOut => {
+                self.is_animating = true;
+                Effects::with_local(self.animate_out())
+            }
             Msg::FrameMsg(fmsg) => {
-                let effects = self.frame.update(*fmsg);
-                effects.localize(|fmsg| Msg::FrameMsg(Box::new(fmsg)))
+                // the external messages of the frame are the messages of this image
+                let Effects {
+                    local, external, ..
+                } = self.frame.update(*fmsg);
+                Effects::with_local(external.into_iter().chain(
+                    local.into_iter().map(|fmsg| Msg::FrameMsg(Box::new(fmsg))),
+                ))
             }
             Msg::AnimationDone => {
                 self.is_animating = false;
@@ -83,6 +129,13 @@ impl Component<Msg, ()> for Image {
                 self.stop_animation();
                 Effects::none()
             }
+            Msg::AnimateOutDone => {
+                self.is_animating = false;
+                self.options.typing_sound.stop();
+                self.hidden = true;
+                self.frame.set_content(self.properties.slice_view(Some(0)));
+                Effects::with_external(self.on_animate_out.emit())
+            }
             Msg::NextAnimation(is_in, start, duration) => {
                 let follow_ups = self.next_animation(is_in, start, duration);
                 Effects::with_local(follow_ups)
@@ -132,8 +185,12 @@ impl Properties {
         };
         let mut cells = vec![];
         let (slice_x, slice_y) = self.slices();
-        let max = slice_x * slice_y;
-        let limit = if let Some(limit) = limit { limit } else { max };
+        let total = slice_x * slice_y;
+        let limit = if let Some(limit) = limit {
+            limit
+        } else {
+            total
+        };
         let mut index = 0;
         for y in 0..slice_y {
             let top = (self.slice_size + self.gap) * y as f32;
@@ -201,14 +258,23 @@ impl Properties {
     }
 }
 
-impl Image {
+impl<XMSG> Image<XMSG> {
     pub fn animate_in(&mut self) -> Vec<Msg> {
-        sounds::play(&self.audio);
+        self.hidden = false;
         self.start_animation(true)
     }
 
+    /// hide the image by removing the slices in reverse
+    pub fn animate_out(&mut self) -> Vec<Msg> {
+        if self.hidden {
+            return vec![Msg::AnimateOutDone];
+        }
+        self.start_animation(false)
+    }
+
     fn stop_animation(&mut self) -> Vec<Msg> {
         self.is_animating = false;
+        self.options.typing_sound.stop();
         let class_ns = |class_names| {
             attributes::class_namespaced(COMPONENT_NAME, class_names)
         };
@@ -221,17 +287,25 @@ impl Image {
     }
 
     fn start_animation(&mut self, is_in: bool) -> Vec<Msg> {
+        self.start_animation_at(is_in, sauron::dom::now())
+    }
+
+    fn start_animation_at(&mut self, is_in: bool, start: f64) -> Vec<Msg> {
         if self.content_len() == 0 {
-            return vec![];
+            return if is_in {
+                vec![]
+            } else {
+                vec![Msg::AnimateOutDone]
+            };
         }
 
-        let interval = 1_000.0 / 60.0;
-        let real_duration = interval * self.content_len() as f64;
-        let timeout = 500.0;
-        let duration = real_duration.min(timeout);
-        let start = crate::dom::now();
+        let duration = self.options.duration(self.content_len(), 0);
 
         self.is_animating = true;
+        self.start.restart(start);
+        self.typing_ticker
+            .reset(if is_in { 0 } else { self.content_len() });
+        self.options.typing_sound.start();
 
         vec![Msg::NextAnimation(is_in, start, duration)]
     }
@@ -242,15 +316,30 @@ impl Image {
         start: f64,
         duration: f64,
     ) -> Vec<Msg> {
-        let timestamp = crate::dom::now();
+        self.next_animation_at(is_in, start, duration, sauron::dom::now())
+    }
 
-        let mut anim_progress = (timestamp - start).max(0.0);
-        if !is_in {
-            anim_progress = duration - anim_progress;
+    fn next_animation_at(
+        &mut self,
+        is_in: bool,
+        start: f64,
+        duration: f64,
+        timestamp: f64,
+    ) -> Vec<Msg> {
+        if self.start.is_stale(start) {
+            return vec![];
         }
-
-        let limit = (anim_progress * self.content_len() as f64 / duration)
-            .round() as usize;
+        let limit = self.options.revealed(
+            timestamp - start,
+            is_in,
+            self.content_len(),
+            &[],
+        );
+        self.options.typing_sound.revealed(
+            &mut self.typing_ticker,
+            limit,
+            timestamp,
+        );
 
         let continue_animation = if is_in {
             limit <= (self.content_len() - 1)
@@ -262,8 +351,13 @@ impl Image {
             self.frame
                 .set_content(self.properties.slice_view(Some(limit)));
             vec![Msg::NextAnimation(is_in, start, duration)]
-        } else {
+        } else if is_in {
             vec![Msg::StopAnimation]
+        } else {
+            vec![Msg::AnimateOutDone]
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn animating_in_while_animating_out_drops_the_animate_out() {
+    let mut image = Image::<()>::new("image.jpg");
+    image.start_animation_at(false, 1000.0);
+    image.start_animation_at(true, 1030.0);
+    let duration = image.options.duration(image.content_len(), 0);
+
+    let stale =
+        image.next_animation_at(false, 1000.0, duration, 1000.0 + duration);
+    assert!(stale.is_empty());
+    let follow_ups =
+        image.next_animation_at(true, 1030.0, duration, 1030.0 + duration);
+    assert!(matches!(follow_ups[..], [Msg::StopAnimation]));
+}
+
+#[test]
+fn the_external_messages_of_the_frame_are_updated_on_the_image() {
+    let mut image = Image::<()>::new("image.jpg");
+    let effects = image.update(Msg::FrameMsg(Box::new(frame::Msg::External(
+        Msg::AnimationDone,
+    ))));
+    assert!(matches!(effects.local[..], [Msg::AnimationDone]));
+    assert!(effects.external.is_empty());
+}
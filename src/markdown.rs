@@ -0,0 +1,339 @@
+//! Convert markdown into sauron nodes, so the content can be animated
+//! element by element with the `AnimateList`.
+//!
+//! Supports CommonMark with the tables, strikethrough and task lists extensions.
+//! The html in the markdown is shown as text and the urls of links and images
+//! are limited to the `http`, `https` and `mailto` schemes or relative urls,
+//! unless the content is trusted and converted with `markdown_to_node_with_raw_html`.
+use crate::ssr::escape_html;
+use pulldown_cmark::{
+    Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag,
+};
+use sauron::{html::attributes::class, prelude::*, Attribute, Node};
+
+/// convert the markdown into a `div` containing the rendered blocks,
+/// the html in the markdown is escaped and shown as text
+/// and the links and images with unsafe urls such as `javascript:` have no url.
+pub fn markdown_to_node<MSG>(md: &str) -> Node<MSG> {
+    convert(md, false)
+}
+
+/// convert the markdown into a `div` containing the rendered blocks,
+/// the html in the markdown is rendered as markup and the urls are used as is.
+/// Only use this for trusted content, since the html is injected as is.
+pub fn markdown_to_node_with_raw_html<MSG>(md: &str) -> Node<MSG> {
+    convert(md, true)
+}
+
+fn convert<MSG>(md: &str, raw_html: bool) -> Node<MSG> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut builder = Builder::new(raw_html);
+    for event in Parser::new_ext(md, options) {
+        builder.push_event(event);
+    }
+    builder.finish()
+}
+
+/// the image which is waiting for its alt text
+struct PendingImage {
+    url: String,
+    title: String,
+    alt: String,
+    /// the elements inside the alt text which are not yet closed,
+    /// only their text is used in the alt
+    depth: usize,
+}
+
+/// builds the node tree from the markdown events,
+/// the elements which are not yet closed are kept in the stack.
+struct Builder<MSG> {
+    stack: Vec<Node<MSG>>,
+    alignments: Vec<Alignment>,
+    cell_index: usize,
+    in_table_head: bool,
+    image: Option<PendingImage>,
+    /// the html is rendered as markup instead of text
+    raw_html: bool,
+    /// the fragments of the raw html and the text between them, such as
+    /// `<span>`, `text` and `</span>` which are added as a single html node
+    pending_html: Option<String>,
+}
+
+impl<MSG> Builder<MSG> {
+    fn new(raw_html: bool) -> Self {
+        Builder {
+            stack: vec![div([class("markdown")], [])],
+            alignments: vec![],
+            cell_index: 0,
+            in_table_head: false,
+            image: None,
+            raw_html,
+            pending_html: None,
+        }
+    }
+
+    fn finish(mut self) -> Node<MSG> {
+        self.flush_html();
+        // close any element that is left unclosed
+        while self.stack.len() > 1 {
+            self.close();
+        }
+        self.stack.pop().expect("must have the root")
+    }
+
+    fn push_event(&mut self, event: Event) {
+        if let Some(pending_html) = &mut self.pending_html {
+            match event {
+                Event::Html(html) => return pending_html.push_str(&html),
+                Event::Text(txt) => {
+                    return pending_html.push_str(&escape_html(&txt))
+                }
+                Event::SoftBreak => return pending_html.push('\n'),
+                _ => self.flush_html(),
+            }
+        }
+        if let Some(image) = &mut self.image {
+            match event {
+                Event::Start(_) => image.depth += 1,
+                Event::End(_) if image.depth > 0 => image.depth -= 1,
+                Event::End(tag) => self.end(tag),
+                Event::Text(txt) | Event::Code(txt) => image.alt.push_str(&txt),
+                Event::SoftBreak | Event::HardBreak => image.alt.push(' '),
+                _ => (),
+            }
+            return;
+        }
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(txt) => self.add_child(text(txt)),
+            Event::Code(code_txt) => self.add_child(code([], [text(code_txt)])),
+            Event::Html(html) => {
+                if self.raw_html {
+                    self.pending_html = Some(html.to_string());
+                } else {
+                    self.add_child(text(html));
+                }
+            }
+            Event::FootnoteReference(label) => self.add_child(sup(
+                [class("footnote-reference")],
+                [a([attr("href", format!("#{}", label))], [text(label)])],
+            )),
+            Event::SoftBreak => self.add_child(text("\n")),
+            Event::HardBreak => self.add_child(br([], [])),
+            Event::Rule => self.add_child(hr([], [])),
+            Event::TaskListMarker(is_checked) => self.add_child(input(
+                [
+                    attr("type", "checkbox"),
+                    disabled(true),
+                    checked(is_checked),
+                ],
+                [],
+            )),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.open("p", vec![]),
+            Tag::Heading(level, id, classes) => {
+                let mut attrs = vec![];
+                if let Some(id) = id {
+                    attrs.push(attr("id", id.to_string()));
+                }
+                if !classes.is_empty() {
+                    attrs.push(class(classes.join(" ")));
+                }
+                self.open(heading_tag(level), attrs)
+            }
+            Tag::BlockQuote => self.open("blockquote", vec![]),
+            Tag::CodeBlock(kind) => {
+                self.open("pre", vec![]);
+                match kind {
+                    CodeBlockKind::Fenced(language) if !language.is_empty() => {
+                        self.open(
+                            "code",
+                            vec![class(format!("language-{}", language))],
+                        )
+                    }
+                    _ => self.open("code", vec![]),
+                }
+            }
+            Tag::List(Some(1)) => self.open("ol", vec![]),
+            Tag::List(Some(start)) => {
+                self.open("ol", vec![attr("start", start as usize)])
+            }
+            Tag::List(None) => self.open("ul", vec![]),
+            Tag::Item => self.open("li", vec![]),
+            Tag::FootnoteDefinition(label) => {
+                self.open(
+                    "div",
+                    vec![
+                        class("footnote-definition"),
+                        attr("id", label.to_string()),
+                    ],
+                );
+                self.add_child(sup(
+                    [class("footnote-definition-label")],
+                    [text(label)],
+                ));
+            }
+            Tag::Table(alignments) => {
+                self.alignments = alignments;
+                self.open("table", vec![]);
+            }
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.cell_index = 0;
+                self.open("thead", vec![]);
+                self.open("tr", vec![]);
+            }
+            Tag::TableRow => {
+                self.cell_index = 0;
+                self.open("tr", vec![]);
+            }
+            Tag::TableCell => {
+                let tag = if self.in_table_head { "th" } else { "td" };
+                let align = match self.alignments.get(self.cell_index) {
+                    Some(Alignment::Left) => Some("left"),
+                    Some(Alignment::Center) => Some("center"),
+                    Some(Alignment::Right) => Some("right"),
+                    Some(Alignment::None) | None => None,
+                };
+                let attrs = match align {
+                    Some(align) => vec![attr("align", align)],
+                    None => vec![],
+                };
+                self.open(tag, attrs);
+                self.cell_index += 1;
+            }
+            Tag::Emphasis => self.open("em", vec![]),
+            Tag::Strong => self.open("strong", vec![]),
+            Tag::Strikethrough => self.open("del", vec![]),
+            Tag::Link(_, url, link_title) => {
+                let mut attrs = vec![];
+                if self.is_allowed_url(&url) {
+                    attrs.push(attr("href", url.to_string()));
+                }
+                if !link_title.is_empty() {
+                    attrs.push(attr("title", link_title.to_string()));
+                }
+                self.open("a", attrs)
+            }
+            Tag::Image(_, url, image_title) => {
+                self.image = Some(PendingImage {
+                    url: url.to_string(),
+                    title: image_title.to_string(),
+                    alt: String::new(),
+                    depth: 0,
+                })
+            }
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::CodeBlock(_) => {
+                // close both the code and the pre
+                self.close();
+                self.close();
+            }
+            Tag::TableHead => {
+                // close both the tr and the thead
+                self.close();
+                self.close();
+                self.in_table_head = false;
+                self.open("tbody", vec![]);
+            }
+            Tag::Table(_) => {
+                // close both the tbody and the table
+                self.close();
+                self.close();
+                self.alignments.clear();
+            }
+            Tag::Image(..) => {
+                if let Some(image) = self.image.take() {
+                    let mut attrs = vec![];
+                    if self.is_allowed_url(&image.url) {
+                        attrs.push(attr("src", image.url));
+                    }
+                    attrs.push(attr("alt", image.alt));
+                    if !image.title.is_empty() {
+                        attrs.push(attr("title", image.title));
+                    }
+                    self.add_child(html_element(None, "img", attrs, [], true));
+                }
+            }
+            _ => self.close(),
+        }
+    }
+
+    /// the url can be used in a link or an image,
+    /// any url is allowed when the content is trusted
+    fn is_allowed_url(&self, url: &str) -> bool {
+        self.raw_html || is_safe_url(url)
+    }
+
+    /// add the html fragments collected so far as a single html node
+    fn flush_html(&mut self) {
+        if let Some(pending_html) = self.pending_html.take() {
+            self.add_child(safe_html(pending_html));
+        }
+    }
+
+    /// open a new element, the succeeding nodes will be added into it
+    fn open(&mut self, tag: &'static str, attrs: Vec<Attribute<MSG>>) {
+        self.stack.push(html_element(None, tag, attrs, [], false));
+    }
+
+    /// close the last opened element and add it to its parent
+    fn close(&mut self) {
+        if self.stack.len() > 1 {
+            let node = self.stack.pop().expect("must have an element");
+            self.add_child(node);
+        }
+    }
+
+    fn add_child(&mut self, node: Node<MSG>) {
+        self.stack
+            .last_mut()
+            .expect("must have the root")
+            .add_children_ref_mut([node]);
+    }
+}
+
+/// the url is relative or uses the `http`, `https` or `mailto` scheme.
+/// The whitespace and control characters are ignored like the browsers do,
+/// so `java\tscript:` is still a `javascript:` url.
+fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|ch| !ch.is_ascii_whitespace() && !ch.is_control())
+        .collect();
+    match url.find([':', '/', '?', '#']) {
+        Some(index) if url[index..].starts_with(':') => {
+            let scheme = url[..index].to_ascii_lowercase();
+            matches!(scheme.as_str(), "http" | "https" | "mailto")
+        }
+        // no scheme, the url is relative
+        _ => true,
+    }
+}
+
+fn heading_tag(level: HeadingLevel) -> &'static str {
+    match level {
+        HeadingLevel::H1 => "h1",
+        HeadingLevel::H2 => "h2",
+        HeadingLevel::H3 => "h3",
+        HeadingLevel::H4 => "h4",
+        HeadingLevel::H5 => "h5",
+        HeadingLevel::H6 => "h6",
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,173 @@
+use super::*;
+
+fn render(md: &str) -> String {
+    markdown_to_node::<()>(md).render_to_string()
+}
+
+#[test]
+fn plain_text_is_a_paragraph() {
+    assert_eq!(
+        render("hello"),
+        "<div class=\"markdown\"><p>hello</p></div>"
+    );
+}
+
+#[test]
+fn headings_and_emphasis() {
+    assert_eq!(
+        render("# Mission\n\nThis is *very* **important** ~~not~~"),
+        "<div class=\"markdown\"><h1>Mission</h1>\
+        <p>This is <em>very</em> <strong>important</strong> <del>not</del></p></div>"
+    );
+}
+
+#[test]
+fn links_and_images() {
+    assert_eq!(
+        render("[docs](https://sauron.rs \"Sauron\") ![ship](img/ship.png)"),
+        "<div class=\"markdown\"><p>\
+        <a href=\"https://sauron.rs\" title=\"Sauron\">docs</a> \
+        <img src=\"img/ship.png\" alt=\"ship\"/></p></div>"
+    );
+}
+
+#[test]
+fn inline_code_and_code_block() {
+    assert_eq!(
+        render("run `cargo test`\n\n```rust\nfn main() {}\n```"),
+        "<div class=\"markdown\"><p>run <code>cargo test</code></p>\
+        <pre><code class=\"language-rust\">fn main() {}\n</code></pre></div>"
+    );
+}
+
+#[test]
+fn lists() {
+    assert_eq!(
+        render("- one\n- two\n\n3. three\n4. four"),
+        "<div class=\"markdown\"><ul><li>one</li><li>two</li></ul>\
+        <ol start=\"3\"><li>three</li><li>four</li></ol></div>"
+    );
+}
+
+#[test]
+fn task_list() {
+    let html = render("- [x] launch\n- [ ] land");
+    assert!(html.contains("<li><input type=\"checkbox\""), "{}", html);
+    assert!(html.contains("launch</li>"), "{}", html);
+}
+
+#[test]
+fn blockquote_and_rule() {
+    assert_eq!(
+        render("> quoted\n\n---"),
+        "<div class=\"markdown\"><blockquote><p>quoted</p></blockquote><hr/></div>"
+    );
+}
+
+#[test]
+fn tables() {
+    assert_eq!(
+        render("| Ship | Crew |\n|:-----|-----:|\n| Nostromo | 7 |"),
+        "<div class=\"markdown\"><table>\
+        <thead><tr><th align=\"left\">Ship</th><th align=\"right\">Crew</th></tr></thead>\
+        <tbody><tr><td align=\"left\">Nostromo</td><td align=\"right\">7</td></tr></tbody>\
+        </table></div>"
+    );
+}
+
+/// the debug output of the children of the first paragraph,
+/// which tells the text apart from the html
+fn paragraph_children(node: &Node<()>) -> Vec<String> {
+    let root = node.get_children().expect("must have children");
+    root[0]
+        .get_children()
+        .expect("must have children")
+        .iter()
+        .map(|child| format!("{:?}", child))
+        .collect()
+}
+
+#[test]
+fn html_is_text_by_default() {
+    let node =
+        markdown_to_node::<()>("a <span>b</span> <script>alert(1)</script>");
+    let children = paragraph_children(&node);
+    assert!(
+        children.iter().all(|child| !child.contains("SafeHtml")),
+        "{:?}",
+        children
+    );
+    assert!(children
+        .iter()
+        .any(|child| child.contains("Text(<script>)")));
+}
+
+#[test]
+fn trusted_inline_html_is_a_single_node() {
+    let node = markdown_to_node_with_raw_html::<()>(
+        "a <span class=\"x\">b & c</span> *d*",
+    );
+    assert_eq!(
+        node.render_to_string(),
+        "<div class=\"markdown\"><p>a <span class=\"x\">b &amp; c</span> \
+        <em>d</em></p></div>"
+    );
+    // the fragments and the text between them are a single html node
+    let children = paragraph_children(&node);
+    assert_eq!(children.len(), 3, "{:?}", children);
+    assert!(
+        children[1].contains("SafeHtml(<span class=\"x\">b &amp; c</span> )"),
+        "{:?}",
+        children
+    );
+}
+
+#[test]
+fn trusted_html_block() {
+    let html = markdown_to_node_with_raw_html::<()>(
+        "<div class=\"briefing\">\n<b>classified</b>\n</div>\n\nafter",
+    )
+    .render_to_string();
+    assert_eq!(
+        html,
+        "<div class=\"markdown\"><div class=\"briefing\">\n<b>classified</b>\n</div>\n\
+        <p>after</p></div>"
+    );
+}
+
+#[test]
+fn unsafe_urls_are_dropped_by_default() {
+    assert_eq!(
+        render("[x](javascript:alert(1)) <JavaScript:alert(1)> ![y](data:text/html,z)"),
+        "<div class=\"markdown\"><p><a>x</a> <a>JavaScript:alert(1)</a> \
+        <img alt=\"y\"/></p></div>"
+    );
+    assert_eq!(
+        render("[a](https://sauron.rs) [b](MAILTO:ship@sauron.rs) [c](../docs?x=a:b) [d](#top)"),
+        "<div class=\"markdown\"><p><a href=\"https://sauron.rs\">a</a> \
+        <a href=\"MAILTO:ship@sauron.rs\">b</a> <a href=\"../docs?x=a:b\">c</a> \
+        <a href=\"#top\">d</a></p></div>"
+    );
+    assert!(!is_safe_url("java\tscript:alert(1)"));
+    assert!(!is_safe_url(" vbscript:msgbox"));
+}
+
+#[test]
+fn trusted_urls_are_used_as_is() {
+    let html = markdown_to_node_with_raw_html::<()>("[x](javascript:void(0))")
+        .render_to_string();
+    assert!(
+        html.contains("<a href=\"javascript:void(0)\">x</a>"),
+        "{}",
+        html
+    );
+}
+
+#[test]
+fn markup_in_the_alt_text_of_an_image() {
+    assert_eq!(
+        render("![*a* `b` [c](d)](ship.png) after"),
+        "<div class=\"markdown\"><p>\
+        <img src=\"ship.png\" alt=\"a b c\"/> after</p></div>"
+    );
+}
@@ -1,4 +1,8 @@
-use crate::sounds;
+use crate::{
+    animation::{AnimateOut, AnimateOutListeners, AnimationStart},
+    shape::ChippedShape,
+    sound_theme::SoundEvent,
+};
 use sauron::jss_ns;
 use sauron::{
     html::attributes,
@@ -6,46 +10,79 @@ use sauron::{
     prelude::*,
     Node,
 };
-use web_sys::HtmlAudioElement;
 
 const COMPONENT_NAME: &str = "fui-navheader";
+/// the duration of the animation in ms
+const DURATION: f64 = 200.0;
+/// the time it takes the border to collapse, which is its transition time
+const COLLAPSE_DURATION: f64 = 250.0;
 
 #[derive(Clone, Debug)]
 pub enum Msg {
     AnimateIn,
+    /// collapse the border of the header
+    AnimateOut,
     StopAnimation,
-    NextAnimation(f64, f64),
+    AnimateOutDone,
+    NextAnimation(bool, f64, f64),
 }
 
-pub struct NavHeader {
-    audio: HtmlAudioElement,
+pub struct NavHeader<XMSG> {
     hide: bool,
+    /// the border is collapsing while animating out,
+    /// the header is hidden once it is collapsed
+    collapsing: bool,
+    start: AnimationStart,
     content: String,
+    /// the corners of the header that are cut
+    chipped_shape: ChippedShape,
+    on_animate_out: AnimateOutListeners<XMSG>,
 }
 
-impl NavHeader {
+impl<XMSG> NavHeader<XMSG> {
     pub fn with_content(content: &str) -> Self {
         NavHeader {
-            audio: sounds::preload("sounds/deploy.mp3"),
             hide: false,
+            collapsing: false,
+            start: AnimationStart::default(),
             content: content.to_string(),
+            chipped_shape: ChippedShape::new(),
+            on_animate_out: AnimateOutListeners::default(),
         }
     }
+
+    /// cut these corners of the header
+    pub fn with_chipped(mut self, shape: ChippedShape) -> Self {
+        self.chipped_shape = shape;
+        self
+    }
 }
 
-impl Component<Msg, ()> for NavHeader {
-    fn update(&mut self, msg: Msg) -> Effects<Msg, ()> {
+impl<XMSG> AnimateOut<XMSG> for NavHeader<XMSG> {
+    fn animate_out_listeners(&mut self) -> &mut AnimateOutListeners<XMSG> {
+        &mut self.on_animate_out
+    }
+}
+
+impl<XMSG> Component<Msg, XMSG> for NavHeader<XMSG>
+where
+    XMSG: 'static,
+{
+    fn update(&mut self, msg: Msg) -> Effects<Msg, XMSG> {
         match msg {
-            Msg::AnimateIn => {
-                self.hide = true;
-                Effects::with_local(self.start_animation())
-            }
+            Msg::AnimateIn => Effects::with_local(self.start_animation(true)),
+            Msg::AnimateOut => Effects::with_local(self.start_animation(false)),
             Msg::StopAnimation => {
                 self.hide = false;
                 Effects::none()
             }
-            Msg::NextAnimation(start, duration) => {
-                Effects::with_local(self.next_animation(start, duration))
+            Msg::AnimateOutDone => {
+                self.collapsing = false;
+                self.hide = true;
+                Effects::with_external(self.on_animate_out.emit())
+            }
+            Msg::NextAnimation(is_in, start, duration) => {
+                Effects::with_local(self.next_animation(is_in, start, duration))
             }
         }
     }
@@ -64,10 +101,20 @@ impl Component<Msg, ()> for NavHeader {
         header(
             [
                 class(COMPONENT_NAME),
-                classes_ns_flag([("hide", self.hide)]),
+                classes_ns_flag([
+                    ("hide", self.hide),
+                    ("collapsing", self.collapsing),
+                ]),
             ],
             [div(
-                [class_ns("content_and_relief")],
+                [
+                    class_ns("content_and_relief"),
+                    if self.chipped_shape.is_empty() {
+                        empty_attr()
+                    } else {
+                        style! {clip_path: self.chipped_shape.clip_path()}
+                    },
+                ],
                 [
                     div(
                         [class_ns("text text-anim")],
@@ -89,26 +136,59 @@ impl Component<Msg, ()> for NavHeader {
     }
 }
 
-impl NavHeader {
+impl<XMSG> NavHeader<XMSG> {
     fn child(&self) -> Node<Msg> {
         div([], [text(&self.content)])
     }
 
-    fn start_animation(&mut self) -> Vec<Msg> {
-        let duration = 200.0;
-        let start = crate::dom::now();
-        sounds::play(&self.audio);
-        vec![Msg::NextAnimation(start, duration)]
+    fn start_animation(&mut self, is_in: bool) -> Vec<Msg> {
+        self.start_animation_at(is_in, sauron::dom::now())
     }
 
-    fn next_animation(&mut self, start: f64, duration: f64) -> Vec<Msg> {
-        let timestamp = crate::dom::now();
+    /// the header is hidden while animating in, the css transition expands the border
+    /// when the animation stops. When animating out, the header stays visible
+    /// while its border collapses and is hidden when done.
+    fn start_animation_at(&mut self, is_in: bool, start: f64) -> Vec<Msg> {
+        // the header is already hidden, or still hidden while animating in
+        if !is_in && self.hide {
+            self.start.restart(start);
+            return vec![Msg::AnimateOutDone];
+        }
+        let duration = if is_in { DURATION } else { COLLAPSE_DURATION };
+        self.hide = is_in;
+        self.collapsing = !is_in;
+        self.start.restart(start);
+        SoundEvent::Deploy.play();
+        vec![Msg::NextAnimation(is_in, start, duration)]
+    }
+
+    fn next_animation(
+        &mut self,
+        is_in: bool,
+        start: f64,
+        duration: f64,
+    ) -> Vec<Msg> {
+        self.next_animation_at(is_in, start, duration, sauron::dom::now())
+    }
+
+    fn next_animation_at(
+        &mut self,
+        is_in: bool,
+        start: f64,
+        duration: f64,
+        timestamp: f64,
+    ) -> Vec<Msg> {
+        if self.start.is_stale(start) {
+            return vec![];
+        }
         let elapsed = timestamp - start;
         let continue_animation = elapsed < duration;
         if continue_animation {
-            vec![Msg::NextAnimation(start, duration)]
-        } else {
+            vec![Msg::NextAnimation(is_in, start, duration)]
+        } else if is_in {
             vec![Msg::StopAnimation]
+        } else {
+            vec![Msg::AnimateOutDone]
         }
     }
 
@@ -153,6 +233,12 @@ impl NavHeader {
               width: 0,
             },
 
+            // the header stays visible while the border collapses
+            ".collapsing .border": {
+              height: 0,
+              width: 0,
+            },
+
             ".border-bottom": {
                 left: percent(50),
                 width: percent(100),
@@ -191,3 +277,6 @@ impl NavHeader {
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
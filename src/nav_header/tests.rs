@@ -0,0 +1,70 @@
+use super::*;
+
+fn render(header: &NavHeader<()>) -> String {
+    crate::ssr::render_to_string(&header.view())
+}
+
+#[test]
+fn header_stays_visible_while_collapsing() {
+    let mut header = NavHeader::<()>::with_content("Mission");
+    header.start_animation_at(false, 1000.0);
+    let html = render(&header);
+    assert!(html.contains("fui-navheader__collapsing"));
+    assert!(!html.contains("fui-navheader__hide"));
+
+    header.update(Msg::AnimateOutDone);
+    let html = render(&header);
+    assert!(html.contains("fui-navheader__hide"));
+    assert!(!html.contains("fui-navheader__collapsing"));
+}
+
+#[test]
+fn animating_in_hides_the_header_until_stopped() {
+    let mut header = NavHeader::<()>::with_content("Mission");
+    header.start_animation_at(true, 1000.0);
+    assert!(render(&header).contains("fui-navheader__hide"));
+    header.update(Msg::StopAnimation);
+    assert!(!render(&header).contains("fui-navheader__hide"));
+}
+
+#[test]
+fn animating_in_while_collapsing_drops_the_animate_out() {
+    let mut header = NavHeader::<()>::with_content("Mission");
+    header.start_animation_at(false, 1000.0);
+    header.start_animation_at(true, 1030.0);
+
+    let stale =
+        header.next_animation_at(false, 1000.0, COLLAPSE_DURATION, 1300.0);
+    assert!(stale.is_empty());
+    let follow_ups = header.next_animation_at(true, 1030.0, DURATION, 1300.0);
+    assert!(matches!(follow_ups[..], [Msg::StopAnimation]));
+    header.update(Msg::StopAnimation);
+
+    assert!(!render(&header).contains("fui-navheader__hide"));
+}
+
+#[test]
+fn animating_out_a_hidden_header_is_done_right_away() {
+    let mut header = NavHeader::<()>::with_content("Mission");
+    header.start_animation_at(false, 1000.0);
+    header.update(Msg::AnimateOutDone);
+
+    let follow_ups = header.start_animation_at(false, 2000.0);
+    assert!(matches!(follow_ups[..], [Msg::AnimateOutDone]));
+    let html = render(&header);
+    assert!(html.contains("fui-navheader__hide"));
+    assert!(!html.contains("fui-navheader__collapsing"));
+}
+
+#[test]
+fn animating_out_while_animating_in_keeps_the_header_hidden() {
+    let mut header = NavHeader::<()>::with_content("Mission");
+    header.start_animation_at(true, 1000.0);
+    let follow_ups = header.start_animation_at(false, 1030.0);
+    assert!(matches!(follow_ups[..], [Msg::AnimateOutDone]));
+    header.update(Msg::AnimateOutDone);
+
+    let stale = header.next_animation_at(true, 1000.0, DURATION, 1300.0);
+    assert!(stale.is_empty());
+    assert!(render(&header).contains("fui-navheader__hide"));
+}
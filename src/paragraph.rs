@@ -1,9 +1,23 @@
-use crate::{animate_list, AnimateList};
+use crate::{
+    animate_list,
+    markdown::{markdown_to_node, markdown_to_node_with_raw_html},
+    AnimateList, AnimateOut, AnimateOutListeners, AnimationOptions,
+};
 use sauron::{prelude::*, Node};
 
 #[derive(Clone, Debug)]
 pub enum Msg {
     AnimateIn,
+    /// hide the content in reverse
+    AnimateOut,
+    /// freeze the animation at its current progress
+    Pause,
+    /// continue the paused animation
+    Resume,
+    /// jump to this fraction of the animation, from 0.0 to 1.0
+    Seek(f64),
+    /// show the content right away
+    SkipToEnd,
     AnimateListMsg(animate_list::Msg),
 }
 
@@ -16,11 +30,63 @@ impl<XMSG> Paragraph<XMSG>
 where
     XMSG: Clone,
 {
+    /// the html in the markdown is shown as text
     pub fn new_with_markdown(md: &str) -> Self {
         Paragraph {
-            animated_list: AnimateList::with_content(text(md)),
+            animated_list: AnimateList::with_content(markdown_to_node(md)),
         }
     }
+
+    /// the html in the markdown is rendered as markup,
+    /// only use this for trusted content
+    pub fn new_with_trusted_markdown(md: &str) -> Self {
+        Paragraph {
+            animated_list: AnimateList::with_content(
+                markdown_to_node_with_raw_html(md),
+            ),
+        }
+    }
+
+    /// freeze the animation at its current progress
+    pub fn pause(&mut self) -> Vec<Msg> {
+        Self::localize(self.animated_list.pause())
+    }
+
+    /// continue the paused animation from where it was paused
+    pub fn resume(&mut self) -> Vec<Msg> {
+        Self::localize(self.animated_list.resume())
+    }
+
+    /// jump to this fraction of the animation, from 0.0 to 1.0
+    pub fn seek(&mut self, fraction: f64) -> Vec<Msg> {
+        Self::localize(self.animated_list.seek(fraction))
+    }
+
+    /// show the content right away
+    pub fn skip_to_end(&mut self) -> Vec<Msg> {
+        Self::localize(self.animated_list.skip_to_end())
+    }
+
+    fn localize(amsgs: Vec<animate_list::Msg>) -> Vec<Msg> {
+        amsgs.into_iter().map(Msg::AnimateListMsg).collect()
+    }
+
+    fn update_list(&mut self, amsg: animate_list::Msg) -> Effects<Msg, XMSG> {
+        let effects = self.animated_list.update(amsg);
+        effects.map_msg(Msg::AnimateListMsg)
+    }
+
+    /// use these options for the timing of the typing animation
+    pub fn with_animation_options(mut self, options: AnimationOptions) -> Self {
+        self.animated_list = self.animated_list.with_animation_options(options);
+        self
+    }
+}
+
+impl<XMSG> AnimateOut<XMSG> for Paragraph<XMSG> {
+    fn animate_out_listeners(&mut self) -> &mut AnimateOutListeners<XMSG> {
+        self.animated_list.animate_out_listeners()
+    }
 }
 
 impl<XMSG> Container<Msg, XMSG> for Paragraph<XMSG>
@@ -29,19 +95,19 @@ where
 {
     fn update(&mut self, msg: Msg) -> Effects<Msg, XMSG> {
         match msg {
-            Msg::AnimateIn => {
-                let effects =
-                    self.animated_list.update(animate_list::Msg::AnimateIn);
-                effects.map_msg(Msg::AnimateListMsg)
-            }
-            Msg::AnimateListMsg(amsg) => {
-                let effects = self.animated_list.update(amsg);
-                effects.map_msg(Msg::AnimateListMsg)
+            Msg::AnimateIn => self.update_list(animate_list::Msg::AnimateIn),
+            Msg::AnimateOut => self.update_list(animate_list::Msg::AnimateOut),
+            Msg::Pause => self.update_list(animate_list::Msg::Pause),
+            Msg::Resume => self.update_list(animate_list::Msg::Resume),
+            Msg::Seek(fraction) => {
+                self.update_list(animate_list::Msg::Seek(fraction))
             }
+            Msg::SkipToEnd => self.update_list(animate_list::Msg::SkipToEnd),
+            Msg::AnimateListMsg(amsg) => self.update_list(amsg),
         }
     }
 
     fn view(&self) -> Node<XMSG> {
-        p([], [self.animated_list.view()])
+        div([class("paragraph")], [self.animated_list.view()])
     }
 }
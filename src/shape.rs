@@ -0,0 +1,289 @@
+//! The chipped, cut-corner shapes of the widgets.
+//!
+//! A `ChippedShape` describes which of the four corners are cut and by how much.
+//! It can be rendered as the points of an svg polygon scaled to a width and height,
+//! or as a css `clip-path` which scales to whatever element it is applied to.
+
+/// The corners of a rectangle, in clockwise order starting from the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+/// The size in px of the cut at a corner
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chip {
+    /// the length of the cut along the horizontal side
+    pub width: f64,
+    /// the length of the cut along the vertical side
+    pub height: f64,
+}
+
+/// Which corners are chipped and by how much, the corners without a chip are square.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ChippedShape {
+    pub top_left: Option<Chip>,
+    pub top_right: Option<Chip>,
+    pub bottom_right: Option<Chip>,
+    pub bottom_left: Option<Chip>,
+}
+
+impl Corner {
+    /// all the corners in clockwise order starting from the top left
+    pub fn all() -> [Corner; 4] {
+        [
+            Corner::TopLeft,
+            Corner::TopRight,
+            Corner::BottomRight,
+            Corner::BottomLeft,
+        ]
+    }
+}
+
+impl ChippedShape {
+    /// a rectangle with no chipped corners
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// only the bottom right corner is chipped, the look of the chipped `Button`
+    pub fn bottom_right(size: f64) -> Self {
+        Self::new().chip(Corner::BottomRight, size)
+    }
+
+    /// chip the corner with an equal cut on both sides
+    pub fn chip(self, corner: Corner, size: f64) -> Self {
+        self.chip_sized(corner, size, size)
+    }
+
+    /// chip the corner with a different cut on the horizontal and vertical side
+    pub fn chip_sized(
+        mut self,
+        corner: Corner,
+        width: f64,
+        height: f64,
+    ) -> Self {
+        let chip = Some(Chip {
+            width: width.max(0.0),
+            height: height.max(0.0),
+        });
+        match corner {
+            Corner::TopLeft => self.top_left = chip,
+            Corner::TopRight => self.top_right = chip,
+            Corner::BottomRight => self.bottom_right = chip,
+            Corner::BottomLeft => self.bottom_left = chip,
+        }
+        self
+    }
+
+    /// the chip at this corner, if any
+    pub fn get(&self, corner: Corner) -> Option<Chip> {
+        match corner {
+            Corner::TopLeft => self.top_left,
+            Corner::TopRight => self.top_right,
+            Corner::BottomRight => self.bottom_right,
+            Corner::BottomLeft => self.bottom_left,
+        }
+    }
+
+    /// the size of the chip at this corner, zero when the corner is square
+    fn size(&self, corner: Corner) -> Chip {
+        self.get(corner).unwrap_or(Chip {
+            width: 0.0,
+            height: 0.0,
+        })
+    }
+
+    /// no corner is chipped
+    pub fn is_empty(&self) -> bool {
+        Corner::all()
+            .iter()
+            .all(|corner| self.get(*corner).is_none())
+    }
+
+    /// the chips fitted into the width and height, when the chips on the same side
+    /// are longer than the side they are scaled down proportionally
+    fn fitted(&self, width: f64, height: f64) -> [Option<Chip>; 4] {
+        let size = |corner| self.size(corner);
+        let fit = |length: f64, a: f64, b: f64| {
+            if a + b > length && a + b > 0.0 {
+                length.max(0.0) / (a + b)
+            } else {
+                1.0
+            }
+        };
+        let (tl, tr, br, bl) = (
+            size(Corner::TopLeft),
+            size(Corner::TopRight),
+            size(Corner::BottomRight),
+            size(Corner::BottomLeft),
+        );
+        let scale_x =
+            fit(width, tl.width, tr.width).min(fit(width, bl.width, br.width));
+        let scale_y = fit(height, tl.height, bl.height)
+            .min(fit(height, tr.height, br.height));
+        Corner::all().map(|corner| {
+            self.get(corner).map(|chip| Chip {
+                width: chip.width * scale_x,
+                height: chip.height * scale_y,
+            })
+        })
+    }
+
+    /// the points of the outline in clockwise order starting from the top left,
+    /// a chipped corner has 2 points while a square corner has 1
+    pub fn points(&self, width: f64, height: f64) -> Vec<(f64, f64)> {
+        let [tl, tr, br, bl] = self.fitted(width, height);
+        let mut points = vec![];
+        match tl {
+            Some(chip) => {
+                points.extend([(0.0, chip.height), (chip.width, 0.0)])
+            }
+            None => points.push((0.0, 0.0)),
+        }
+        match tr {
+            Some(chip) => {
+                points.extend([(width - chip.width, 0.0), (width, chip.height)])
+            }
+            None => points.push((width, 0.0)),
+        }
+        match br {
+            Some(chip) => points.extend([
+                (width, height - chip.height),
+                (width - chip.width, height),
+            ]),
+            None => points.push((width, height)),
+        }
+        match bl {
+            Some(chip) => points
+                .extend([(chip.width, height), (0.0, height - chip.height)]),
+            None => points.push((0.0, height)),
+        }
+        points
+    }
+
+    /// the value of the `points` attribute of an svg polygon
+    pub fn svg_points(&self, width: f64, height: f64) -> String {
+        join_points(&self.points(width, height))
+    }
+
+    /// the filled triangles at each chipped corner, set apart from the cut by the gap
+    pub fn triangles(&self, width: f64, height: f64, gap: f64) -> Vec<String> {
+        // the chips fitted into a small element can be smaller than the gap
+        let [tl, tr, br, bl] = self.fitted(width, height).map(|chip| {
+            chip.filter(|chip| chip.width > gap && chip.height > gap)
+        });
+        let mut triangles = vec![];
+        if let Some(chip) = tl {
+            triangles.push([
+                (0.0, 0.0),
+                (chip.width - gap, 0.0),
+                (0.0, chip.height - gap),
+            ]);
+        }
+        if let Some(chip) = tr {
+            triangles.push([
+                (width, 0.0),
+                (width, chip.height - gap),
+                (width - chip.width + gap, 0.0),
+            ]);
+        }
+        if let Some(chip) = br {
+            triangles.push([
+                (width - chip.width + gap, height),
+                (width, height - chip.height + gap),
+                (width, height),
+            ]);
+        }
+        if let Some(chip) = bl {
+            triangles.push([
+                (0.0, height),
+                (0.0, height - chip.height + gap),
+                (chip.width - gap, height),
+            ]);
+        }
+        triangles
+            .iter()
+            .map(|triangle| join_points(triangle))
+            .collect()
+    }
+
+    /// a css `clip-path` which cuts the corners of the element at any size,
+    /// the chips are fitted into the sides of the element like the svg `points`
+    pub fn clip_path(&self) -> String {
+        let size = |corner| self.size(corner);
+        let (tl, tr, br, bl) = (
+            size(Corner::TopLeft),
+            size(Corner::TopRight),
+            size(Corner::BottomRight),
+            size(Corner::BottomLeft),
+        );
+        // the lengths of the chips on the same horizontal and vertical sides
+        let sides_x = [tl.width + tr.width, bl.width + br.width];
+        let sides_y = [tl.height + bl.height, tr.height + br.height];
+        let x = |chip: Chip| fitted_length(chip.width, sides_x);
+        let y = |chip: Chip| fitted_length(chip.height, sides_y);
+        let from_end = |length: String| {
+            if length == "0px" {
+                "100%".to_string()
+            } else {
+                format!("calc(100% - {})", length)
+            }
+        };
+        let mut points = vec![];
+        match self.top_left {
+            Some(chip) => points.extend([
+                format!("0px {}", y(chip)),
+                format!("{} 0px", x(chip)),
+            ]),
+            None => points.push("0px 0px".to_string()),
+        }
+        match self.top_right {
+            Some(chip) => points.extend([
+                format!("{} 0px", from_end(x(chip))),
+                format!("100% {}", y(chip)),
+            ]),
+            None => points.push("100% 0px".to_string()),
+        }
+        match self.bottom_right {
+            Some(chip) => points.extend([
+                format!("100% {}", from_end(y(chip))),
+                format!("{} 100%", from_end(x(chip))),
+            ]),
+            None => points.push("100% 100%".to_string()),
+        }
+        match self.bottom_left {
+            Some(chip) => points.extend([
+                format!("{} 100%", x(chip)),
+                format!("0px {}", from_end(y(chip))),
+            ]),
+            None => points.push("0px 100%".to_string()),
+        }
+        format!("polygon({})", points.join(", "))
+    }
+}
+
+/// the css length of a chip, scaled down by the same ratio as `fitted`
+/// when the chips on either of the `sides` are longer than the side of the element
+fn fitted_length(length: f64, sides: [f64; 2]) -> String {
+    if length == 0.0 {
+        return "0px".to_string();
+    }
+    // the longest side has the most scaled down chips
+    let longest = sides[0].max(sides[1]);
+    format!("min({}px, {}%)", length, length / longest * 100.0)
+}
+
+fn join_points(points: &[(f64, f64)]) -> String {
+    points
+        .iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests;
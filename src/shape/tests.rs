@@ -0,0 +1,179 @@
+use super::*;
+
+#[test]
+fn bottom_right_matches_the_chipped_button() {
+    let shape = ChippedShape::bottom_right(20.0);
+    assert_eq!(
+        shape.svg_points(200.0, 40.0),
+        "0,0 200,0 200,20 180,40 0,40"
+    );
+    assert_eq!(shape.triangles(200.0, 40.0, 4.0), ["184,40 200,24 200,40"]);
+}
+
+#[test]
+fn square_shape_is_a_rectangle() {
+    let shape = ChippedShape::new();
+    assert!(shape.is_empty());
+    assert_eq!(shape.svg_points(10.0, 5.0), "0,0 10,0 10,5 0,5");
+    assert!(shape.triangles(10.0, 5.0, 1.0).is_empty());
+    assert_eq!(
+        shape.clip_path(),
+        "polygon(0px 0px, 100% 0px, 100% 100%, 0px 100%)"
+    );
+}
+
+#[test]
+fn each_corner_has_its_own_size() {
+    let shape = ChippedShape::new().chip(Corner::TopLeft, 10.0).chip_sized(
+        Corner::BottomRight,
+        30.0,
+        5.0,
+    );
+    assert_eq!(
+        shape.points(100.0, 50.0),
+        [
+            (0.0, 10.0),
+            (10.0, 0.0),
+            (100.0, 0.0),
+            (100.0, 45.0),
+            (70.0, 50.0),
+            (0.0, 50.0)
+        ]
+    );
+}
+
+#[test]
+fn points_scale_to_the_width_and_height() {
+    let shape = ChippedShape::bottom_right(20.0);
+    assert_eq!(
+        shape.svg_points(120.0, 60.0),
+        "0,0 120,0 120,40 100,60 0,60"
+    );
+}
+
+#[test]
+fn oversized_chips_are_fitted_into_the_sides() {
+    let shape = ChippedShape::new()
+        .chip(Corner::TopLeft, 40.0)
+        .chip(Corner::TopRight, 40.0);
+    let points = shape.points(40.0, 100.0);
+    assert_eq!(points[1], (20.0, 0.0));
+    assert_eq!(points[2], (20.0, 0.0));
+}
+
+#[test]
+fn clip_path_cuts_from_the_far_sides() {
+    let shape = ChippedShape::new()
+        .chip(Corner::TopRight, 8.0)
+        .chip(Corner::BottomLeft, 12.0);
+    assert_eq!(
+        shape.clip_path(),
+        "polygon(0px 0px, calc(100% - min(8px, 66.66666666666666%)) 0px, \
+        100% min(8px, 66.66666666666666%), 100% 100%, \
+        min(12px, 100%) 100%, 0px calc(100% - min(12px, 100%)))"
+    );
+}
+
+/// the length of the clip path coordinate on a side of this length
+fn resolve(coordinate: &str, side: f64) -> f64 {
+    let length = |value: &str| {
+        if let Some(px) = value.strip_suffix("px") {
+            px.parse::<f64>().unwrap()
+        } else {
+            let percent = value.strip_suffix('%').unwrap();
+            percent.parse::<f64>().unwrap() / 100.0 * side
+        }
+    };
+    if let Some(fitted) = coordinate
+        .strip_prefix("calc(100% - ")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        side - resolve(fitted, side)
+    } else if let Some(lengths) = coordinate
+        .strip_prefix("min(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        lengths
+            .split(", ")
+            .map(length)
+            .fold(f64::INFINITY, f64::min)
+    } else {
+        length(coordinate)
+    }
+}
+
+/// the points of the clip path on an element of this width and height
+fn resolve_clip_path(
+    clip_path: &str,
+    width: f64,
+    height: f64,
+) -> Vec<(f64, f64)> {
+    let mut points = vec![];
+    let mut rest = clip_path
+        .strip_prefix("polygon(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap();
+    // the coordinates are separated by a space outside of the parentheses
+    while !rest.is_empty() {
+        let mut coordinates = vec![];
+        for _ in 0..2 {
+            let mut depth = 0;
+            let end = rest
+                .char_indices()
+                .find(|(_, ch)| {
+                    match ch {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        ' ' | ',' if depth == 0 => return true,
+                        _ => (),
+                    }
+                    false
+                })
+                .map(|(index, _)| index)
+                .unwrap_or(rest.len());
+            coordinates.push(&rest[..end]);
+            rest = rest[end..].trim_start_matches([',', ' ']);
+        }
+        points.push((
+            resolve(coordinates[0], width),
+            resolve(coordinates[1], height),
+        ));
+    }
+    points
+}
+
+#[test]
+fn clip_path_matches_the_points_on_an_undersized_element() {
+    let shape = ChippedShape::new()
+        .chip(Corner::TopLeft, 40.0)
+        .chip(Corner::TopRight, 20.0)
+        .chip_sized(Corner::BottomRight, 10.0, 30.0);
+    for (width, height) in [(200.0, 100.0), (30.0, 40.0), (12.0, 6.0)] {
+        let clipped = resolve_clip_path(&shape.clip_path(), width, height);
+        let points = shape.points(width, height);
+        assert_eq!(clipped.len(), points.len());
+        for (clipped, point) in clipped.iter().zip(points) {
+            assert!(
+                (clipped.0 - point.0).abs() < 1e-9
+                    && (clipped.1 - point.1).abs() < 1e-9,
+                "{}x{}: {:?} != {:?}",
+                width,
+                height,
+                clipped,
+                point
+            );
+        }
+    }
+}
+
+#[test]
+fn chips_fitted_smaller_than_the_gap_have_no_triangle() {
+    let shape = ChippedShape::new()
+        .chip(Corner::TopLeft, 20.0)
+        .chip(Corner::TopRight, 20.0)
+        .chip(Corner::BottomRight, 2.0);
+    // the top chips are fitted to 5px wide and the bottom right chip to 0.5px wide
+    let triangles = shape.triangles(10.0, 40.0, 4.0);
+    assert_eq!(triangles, ["0,0 1,0 0,16", "10,0 10,16 9,0"]);
+    assert!(shape.triangles(4.0, 4.0, 4.0).is_empty());
+}
@@ -0,0 +1,155 @@
+//! The clips played for the events of the widgets.
+//!
+//! A `SoundTheme` is installed in the `SoundManager` alongside the `Theme` of the
+//! application, so the set of sounds can be swapped without changing the widgets.
+use crate::sounds::{Category, Sound, SoundManager};
+use serde::{Deserialize, Serialize};
+
+/// The events of the widgets which can have a sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SoundEvent {
+    /// a button is clicked
+    Click,
+    /// a button is hovered
+    Hover,
+    /// a frame or a header is deployed
+    Deploy,
+    /// text or an image is being revealed
+    Typing,
+    /// an error button is clicked or an input is invalid
+    Error,
+    /// a success button is clicked
+    Success,
+    /// a warning button is clicked
+    Warning,
+}
+
+/// The clip of each event, an event without a clip is silent.
+///
+/// When loaded from json, the events which are not specified use the clips of the
+/// default sci-fi theme, set them to `null` to silence them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoundTheme {
+    pub click: Option<String>,
+    pub hover: Option<String>,
+    pub deploy: Option<String>,
+    pub typing: Option<String>,
+    pub error: Option<String>,
+    pub success: Option<String>,
+    pub warning: Option<String>,
+}
+
+impl SoundEvent {
+    /// all the events
+    pub fn all() -> [SoundEvent; 7] {
+        [
+            SoundEvent::Click,
+            SoundEvent::Hover,
+            SoundEvent::Deploy,
+            SoundEvent::Typing,
+            SoundEvent::Error,
+            SoundEvent::Success,
+            SoundEvent::Warning,
+        ]
+    }
+
+    /// the category whose volume the event is played with
+    pub fn category(&self) -> Category {
+        match self {
+            SoundEvent::Click | SoundEvent::Hover => Category::Interface,
+            SoundEvent::Deploy => Category::Animation,
+            SoundEvent::Typing => Category::Typing,
+            SoundEvent::Error | SoundEvent::Success | SoundEvent::Warning => {
+                Category::Notification
+            }
+        }
+    }
+
+    /// play the clip of this event in the current sound theme
+    pub fn play(&self) {
+        SoundManager::with(|manager| manager.play_event(*self));
+    }
+
+    /// loop the clip of this event until it is stopped
+    pub fn play_looped(&self) {
+        SoundManager::with(|manager| manager.play_event_looped(*self));
+    }
+
+    /// stop the clip of this event
+    pub fn stop(&self) {
+        SoundManager::with(|manager| manager.stop_event(*self));
+    }
+}
+
+impl SoundTheme {
+    /// the retro futuristic sounds, there is no hover sound
+    pub fn sci_fi() -> Self {
+        SoundTheme {
+            click: Some("sounds/click.mp3".to_string()),
+            hover: None,
+            deploy: Some("sounds/deploy.mp3".to_string()),
+            typing: Some("sounds/typing.mp3".to_string()),
+            error: Some("sounds/error.mp3".to_string()),
+            success: Some("sounds/information.mp3".to_string()),
+            warning: Some("sounds/warning.mp3".to_string()),
+        }
+    }
+
+    /// only the clicks and the notifications are heard,
+    /// the animations are silent
+    pub fn subtle() -> Self {
+        SoundTheme {
+            deploy: None,
+            typing: None,
+            ..Self::sci_fi()
+        }
+    }
+
+    /// no sounds at all
+    pub fn silent() -> Self {
+        SoundTheme {
+            click: None,
+            hover: None,
+            deploy: None,
+            typing: None,
+            error: None,
+            success: None,
+            warning: None,
+        }
+    }
+
+    /// load a sound theme from a json string
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// the url of the clip of the event
+    pub fn clip(&self, event: SoundEvent) -> Option<&str> {
+        let clip = match event {
+            SoundEvent::Click => &self.click,
+            SoundEvent::Hover => &self.hover,
+            SoundEvent::Deploy => &self.deploy,
+            SoundEvent::Typing => &self.typing,
+            SoundEvent::Error => &self.error,
+            SoundEvent::Success => &self.success,
+            SoundEvent::Warning => &self.warning,
+        };
+        clip.as_deref()
+    }
+
+    /// the sound played for the event, if it has a clip
+    pub fn sound(&self, event: SoundEvent) -> Option<Sound> {
+        self.clip(event)
+            .map(|url| Sound::with_category(url, event.category()))
+    }
+}
+
+impl Default for SoundTheme {
+    fn default() -> Self {
+        Self::sci_fi()
+    }
+}
+
+#[cfg(test)]
+mod tests;
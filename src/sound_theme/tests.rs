@@ -0,0 +1,68 @@
+use super::*;
+
+#[test]
+fn sci_fi_keeps_the_original_clips() {
+    let sound_theme = SoundTheme::sci_fi();
+    assert_eq!(
+        sound_theme.clip(SoundEvent::Click),
+        Some("sounds/click.mp3")
+    );
+    assert_eq!(
+        sound_theme.clip(SoundEvent::Deploy),
+        Some("sounds/deploy.mp3")
+    );
+    assert_eq!(
+        sound_theme.clip(SoundEvent::Typing),
+        Some("sounds/typing.mp3")
+    );
+    assert_eq!(sound_theme.clip(SoundEvent::Hover), None);
+}
+
+#[test]
+fn error_and_success_sound_different() {
+    let sound_theme = SoundTheme::default();
+    assert_ne!(
+        sound_theme.clip(SoundEvent::Error),
+        sound_theme.clip(SoundEvent::Success)
+    );
+}
+
+#[test]
+fn subtle_silences_the_animations() {
+    let sound_theme = SoundTheme::subtle();
+    assert_eq!(sound_theme.sound(SoundEvent::Typing), None);
+    assert_eq!(sound_theme.sound(SoundEvent::Deploy), None);
+    assert!(sound_theme.sound(SoundEvent::Click).is_some());
+}
+
+#[test]
+fn silent_has_no_clips() {
+    let sound_theme = SoundTheme::silent();
+    assert!(SoundEvent::all()
+        .iter()
+        .all(|event| sound_theme.clip(*event).is_none()));
+}
+
+#[test]
+fn sound_uses_the_category_of_the_event() {
+    let sound = SoundTheme::default().sound(SoundEvent::Warning).unwrap();
+    assert_eq!(sound.url(), "sounds/warning.mp3");
+    assert_eq!(sound.category(), Category::Notification);
+}
+
+#[test]
+fn json_overrides_the_default_clips() {
+    let sound_theme = SoundTheme::from_json(
+        r#"{"click": "sounds/soft-click.mp3", "typing": null}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        sound_theme.clip(SoundEvent::Click),
+        Some("sounds/soft-click.mp3")
+    );
+    assert_eq!(sound_theme.clip(SoundEvent::Typing), None);
+    assert_eq!(
+        sound_theme.clip(SoundEvent::Deploy),
+        Some("sounds/deploy.mp3")
+    );
+}
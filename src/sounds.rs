@@ -1,13 +1,425 @@
-//use sauron::wasm_bindgen::closure::Closure;
-use sauron::wasm_bindgen::JsCast;
+//! The sounds of the widgets, played through a single `SoundManager`.
+//!
+//! The manager owns the audio elements of all the clips and applies the master
+//! and per category volume, or the global mute, every time a sound is played.
+//! Browsers reject playing audio before the user has interacted with the page,
+//! so the sounds are only played once the manager is unlocked by the first
+//! pointer or key press, and a rejected play is logged instead of panicking.
+//! The manager listens for that first interaction as soon as a sound is played.
+//!
+//! With the `synth` feature, the events can be synthesized with the Web Audio api
+//! instead of playing the clips, see `SoundManager::use_synth`.
+use crate::sound_theme::{SoundEvent, SoundTheme};
+#[cfg(feature = "synth")]
+use crate::synth::{web_audio::WebAudioSynth, SynthVoices};
+use sauron::{
+    js_sys,
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeMap};
 use web_sys::{HtmlAudioElement, HtmlElement};
 
-/// play sound in request animation frame
-pub fn play(audio: &HtmlAudioElement) {
-    let audio = audio.clone();
-    sauron::request_animation_frame(move || {
-        let _ = audio.play().expect("must play");
-    });
+/// the key of the sound settings in the local storage
+const STORAGE_KEY: &str = "fui-sound-settings";
+
+/// the events which unlock the sounds, these are user gestures
+/// which browsers accept to start playing audio
+const UNLOCK_EVENTS: [&str; 3] = ["pointerdown", "keydown", "touchstart"];
+
+thread_local! {
+    static SOUND_MANAGER: RefCell<SoundManager> = RefCell::new(SoundManager::default());
+}
+
+/// The group of sounds which shares a volume
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    /// clicks and hovers on the controls
+    Interface,
+    /// the deploy sound of frames and headers
+    Animation,
+    /// the typing sound of text and image reveals
+    Typing,
+    /// errors, successes and warnings
+    Notification,
+}
+
+/// A sound clip and the category whose volume it is played with.
+/// The sound is only attached to the document the first time it is played,
+/// so the widgets can be created outside of the browser.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sound {
+    url: String,
+    category: Category,
+}
+
+/// The volumes and mute of the sounds, these are persisted across sessions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoundSettings {
+    /// mute all the sounds
+    pub muted: bool,
+    /// the volume from 0.0 to 1.0 applied to all the sounds
+    pub master_volume: f64,
+    /// the volume from 0.0 to 1.0 of each category, 1.0 when not set
+    pub volumes: BTreeMap<Category, f64>,
+}
+
+/// Owns the audio elements of all the sounds and controls how they are played
+#[derive(Default)]
+pub struct SoundManager {
+    settings: SoundSettings,
+    /// the clips played for the events of the widgets
+    sound_theme: SoundTheme,
+    /// the user has interacted with the page, so the sounds can be played
+    unlocked: bool,
+    /// the listeners waiting for the user interaction which unlocks the sounds are installed
+    listening: bool,
+    /// the settings are loaded from and saved into the local storage
+    persisted: bool,
+    /// the audio element of each clip, keyed by the url
+    clips: BTreeMap<String, HtmlAudioElement>,
+    /// logs the rejected play promises, shared by all the plays
+    on_rejected: Option<Closure<dyn FnMut(JsValue)>>,
+    /// the events are synthesized with these voices instead of playing the clips
+    #[cfg(feature = "synth")]
+    synth_voices: Option<SynthVoices>,
+    #[cfg(feature = "synth")]
+    web_audio: WebAudioSynth,
+}
+
+impl Sound {
+    /// a sound in the `Interface` category
+    pub fn new(url: impl ToString) -> Self {
+        Self::with_category(url, Category::Interface)
+    }
+
+    pub fn with_category(url: impl ToString, category: Category) -> Self {
+        Sound {
+            url: url.to_string(),
+            category,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// play the sound through the sound manager
+    pub fn play(&self) {
+        SoundManager::with(|manager| manager.play(self));
+    }
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        SoundSettings {
+            muted: false,
+            master_volume: 1.0,
+            volumes: BTreeMap::new(),
+        }
+    }
+}
+
+impl SoundSettings {
+    /// the volume the sounds of this category are played with
+    pub fn effective_volume(&self, category: Category) -> f64 {
+        if self.muted {
+            0.0
+        } else {
+            self.master_volume.clamp(0.0, 1.0) * self.volume(category)
+        }
+    }
+
+    /// the volume of the category, not counting the master volume
+    pub fn volume(&self, category: Category) -> f64 {
+        self.volumes
+            .get(&category)
+            .copied()
+            .unwrap_or(1.0)
+            .clamp(0.0, 1.0)
+    }
+}
+
+impl SoundManager {
+    /// use the sound manager shared by all the widgets
+    pub fn with<R>(f: impl FnOnce(&mut SoundManager) -> R) -> R {
+        SOUND_MANAGER.with(|manager| f(&mut manager.borrow_mut()))
+    }
+
+    /// load the persisted settings and unlock the sounds on the first interaction.
+    /// This is called once when the application is started in the browser,
+    /// without it the settings are not persisted and the sounds are unlocked
+    /// by the first interaction after a sound is played.
+    pub fn init() {
+        Self::with(|manager| {
+            manager.persisted = true;
+            if let Some(settings) = load_settings() {
+                manager.settings = settings;
+            }
+            manager.listen_for_unlock();
+        });
+    }
+
+    pub fn settings(&self) -> &SoundSettings {
+        &self.settings
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.settings.muted
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        self.settings.muted = muted;
+        self.save();
+    }
+
+    pub fn toggle_mute(&mut self) {
+        self.set_muted(!self.settings.muted);
+    }
+
+    pub fn master_volume(&self) -> f64 {
+        self.settings.master_volume
+    }
+
+    /// set the volume from 0.0 to 1.0 of all the sounds
+    pub fn set_master_volume(&mut self, volume: f64) {
+        self.settings.master_volume = volume.clamp(0.0, 1.0);
+        self.save();
+    }
+
+    pub fn volume(&self, category: Category) -> f64 {
+        self.settings.volume(category)
+    }
+
+    /// set the volume from 0.0 to 1.0 of the sounds in this category
+    pub fn set_volume(&mut self, category: Category, volume: f64) {
+        self.settings
+            .volumes
+            .insert(category, volume.clamp(0.0, 1.0));
+        self.save();
+    }
+
+    pub fn is_unlocked(&self) -> bool {
+        self.unlocked
+    }
+
+    /// allow the sounds to be played, this is called on the first user interaction
+    pub fn unlock(&mut self) {
+        self.unlocked = true;
+        #[cfg(feature = "synth")]
+        if self.synth_voices.is_some() {
+            self.web_audio.resume();
+        }
+    }
+
+    /// wait for the first user interaction to unlock the sounds
+    fn listen_for_unlock(&mut self) {
+        // there is no document to listen to outside of the browser
+        if cfg!(target_arch = "wasm32") && !self.unlocked && !self.listening {
+            self.listening = true;
+            install_unlock_listeners();
+        }
+    }
+
+    /// the sound is played when the manager is unlocked and it is audible
+    pub fn should_play(&self, sound: &Sound) -> bool {
+        self.is_audible(sound.category)
+    }
+
+    fn is_audible(&self, category: Category) -> bool {
+        self.unlocked && self.settings.effective_volume(category) > 0.0
+    }
+
+    /// synthesize the events with these voices instead of playing the clips,
+    /// or go back to the clips with `None`.
+    /// The events without a clip in the sound theme stay silent.
+    #[cfg(feature = "synth")]
+    pub fn use_synth(&mut self, voices: Option<SynthVoices>) {
+        self.synth_voices = voices;
+    }
+
+    #[cfg(feature = "synth")]
+    pub fn synth_voices(&self) -> Option<&SynthVoices> {
+        self.synth_voices.as_ref()
+    }
+
+    pub fn sound_theme(&self) -> &SoundTheme {
+        &self.sound_theme
+    }
+
+    /// play the events with the clips of this sound theme,
+    /// this is set alongside the `Theme` of the application
+    pub fn set_sound_theme(&mut self, sound_theme: SoundTheme) {
+        self.sound_theme = sound_theme;
+    }
+
+    /// play the clip of the event in the sound theme, if it has one
+    pub fn play_event(&mut self, event: SoundEvent) {
+        self.listen_for_unlock();
+        #[cfg(feature = "synth")]
+        if let Some(voices) = &self.synth_voices {
+            let category = event.category();
+            if self.sound_theme.clip(event).is_some()
+                && self.is_audible(category)
+            {
+                let params = voices.get(event);
+                let volume = self.settings.effective_volume(category);
+                self.web_audio.play(event, params, volume);
+            }
+            return;
+        }
+        if let Some(sound) = self.sound_theme.sound(event) {
+            self.play(&sound);
+        }
+    }
+
+    /// play the clip of the event over and over until the event is stopped
+    pub fn play_event_looped(&mut self, event: SoundEvent) {
+        self.listen_for_unlock();
+        #[cfg(feature = "synth")]
+        if let Some(voices) = &self.synth_voices {
+            let category = event.category();
+            if self.sound_theme.clip(event).is_some()
+                && self.is_audible(category)
+            {
+                let params = voices.get(event);
+                let volume = self.settings.effective_volume(category);
+                self.web_audio.play_looped(event, params, volume);
+            }
+            return;
+        }
+        if let Some(sound) = self.sound_theme.sound(event) {
+            self.play_looped(&sound);
+        }
+    }
+
+    /// stop the clip of the event if it is playing
+    pub fn stop_event(&mut self, event: SoundEvent) {
+        #[cfg(feature = "synth")]
+        self.web_audio.stop(event);
+        if let Some(sound) = self.sound_theme.sound(event) {
+            self.stop(&sound);
+        }
+    }
+
+    /// attach the audio element of the sound to the document ahead of playing it
+    pub fn preload(&mut self, sound: &Sound) -> HtmlAudioElement {
+        self.clips
+            .entry(sound.url.clone())
+            .or_insert_with(|| preload(&sound.url))
+            .clone()
+    }
+
+    /// play the sound from the start with the volume of its category.
+    /// The sound is skipped when it is muted or the sounds are not yet unlocked.
+    pub fn play(&mut self, sound: &Sound) {
+        self.start(sound, false);
+    }
+
+    /// play the sound over and over until it is stopped
+    pub fn play_looped(&mut self, sound: &Sound) {
+        self.start(sound, true);
+    }
+
+    fn start(&mut self, sound: &Sound, looped: bool) {
+        self.listen_for_unlock();
+        if !self.should_play(sound) {
+            return;
+        }
+        let audio = self.preload(sound);
+        audio.set_loop(looped);
+        audio.set_volume(self.settings.effective_volume(sound.category));
+        audio.set_current_time(0.0);
+        let on_rejected = self
+            .on_rejected
+            .get_or_insert_with(|| Closure::wrap(Box::new(on_play_rejected)));
+        match audio.play() {
+            Ok(promise) => {
+                let _ = promise.catch(on_rejected);
+            }
+            Err(e) => log::warn!("unable to play {}: {:?}", sound.url, e),
+        }
+    }
+
+    /// stop the sound if it is playing
+    pub fn stop(&mut self, sound: &Sound) {
+        if let Some(audio) = self.clips.get(&sound.url) {
+            let _ = audio.pause();
+            audio.set_loop(false);
+            audio.set_current_time(0.0);
+        }
+    }
+
+    /// save the settings into the local storage
+    fn save(&self) {
+        if self.persisted {
+            save_settings(&self.settings);
+        }
+    }
+}
+
+/// the play is rejected when the browser doesn't allow the audio to be played yet,
+/// the sounds are locked again until the next user interaction
+fn on_play_rejected(e: JsValue) {
+    let name = js_sys::Reflect::get(&e, &JsValue::from_str("name"))
+        .ok()
+        .and_then(|name| name.as_string());
+    log::warn!("sound was not played: {:?}", name);
+    if name.as_deref() == Some("NotAllowedError") {
+        // this is called asynchronously, the manager is not borrowed
+        SoundManager::with(|manager| manager.unlocked = false);
+    }
+}
+
+/// unlock the sounds on any of the user gestures
+fn install_unlock_listeners() {
+    let unlock: Closure<dyn FnMut(web_sys::Event)> =
+        Closure::wrap(Box::new(|_| SoundManager::with(|m| m.unlock())));
+    let document = sauron::document();
+    for event in UNLOCK_EVENTS {
+        document
+            .add_event_listener_with_callback_and_bool(
+                event,
+                unlock.as_ref().unchecked_ref(),
+                true,
+            )
+            .expect("must add the unlock listener");
+    }
+    // the listeners lives as long as the page
+    unlock.forget();
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    sauron::window().local_storage().ok().flatten()
+}
+
+fn load_settings() -> Option<SoundSettings> {
+    let json = local_storage()?.get_item(STORAGE_KEY).ok().flatten()?;
+    match serde_json::from_str(&json) {
+        Ok(settings) => Some(settings),
+        Err(e) => {
+            log::warn!("ignoring invalid sound settings: {}", e);
+            None
+        }
+    }
+}
+
+fn save_settings(settings: &SoundSettings) {
+    if let Some(storage) = local_storage() {
+        let json = serde_json::to_string(settings)
+            .expect("sound settings must serialize");
+        if let Err(e) = storage.set_item(STORAGE_KEY, &json) {
+            log::warn!("unable to save the sound settings: {:?}", e);
+        }
+    }
 }
 
 /// check if the audio element is already in the document and return it
@@ -34,3 +446,6 @@ fn create_append_audio(sound_url: &str) -> HtmlAudioElement {
         .expect("must be appended to the body");
     audio
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+use super::*;
+
+#[test]
+fn volume_is_the_master_volume_times_the_category() {
+    let mut manager = SoundManager::default();
+    manager.set_master_volume(0.5);
+    manager.set_volume(Category::Typing, 0.4);
+    let settings = manager.settings();
+    assert_eq!(settings.effective_volume(Category::Typing), 0.2);
+    assert_eq!(settings.effective_volume(Category::Interface), 0.5);
+}
+
+#[test]
+fn volumes_are_clamped() {
+    let mut manager = SoundManager::default();
+    manager.set_master_volume(3.0);
+    manager.set_volume(Category::Animation, -1.0);
+    assert_eq!(manager.master_volume(), 1.0);
+    assert_eq!(manager.volume(Category::Animation), 0.0);
+}
+
+#[test]
+fn muted_sounds_are_silent() {
+    let mut manager = SoundManager::default();
+    manager.unlock();
+    let click = Sound::new("sounds/click.mp3");
+    assert!(manager.should_play(&click));
+
+    manager.toggle_mute();
+    assert!(manager.is_muted());
+    assert_eq!(
+        manager.settings().effective_volume(Category::Interface),
+        0.0
+    );
+    assert!(!manager.should_play(&click));
+}
+
+#[test]
+fn sounds_are_skipped_until_unlocked() {
+    let mut manager = SoundManager::default();
+    let deploy = Sound::with_category("sounds/deploy.mp3", Category::Animation);
+    assert!(!manager.should_play(&deploy));
+    // playing a locked sound doesn't touch the document
+    manager.play(&deploy);
+
+    manager.unlock();
+    assert!(manager.is_unlocked());
+    assert!(manager.should_play(&deploy));
+}
+
+#[test]
+fn silent_category_is_skipped() {
+    let mut manager = SoundManager::default();
+    manager.unlock();
+    manager.set_volume(Category::Typing, 0.0);
+    let typing = Sound::with_category("sounds/typing.mp3", Category::Typing);
+    assert!(!manager.should_play(&typing));
+}
+
+#[test]
+fn settings_round_trip_through_json() {
+    let mut settings = SoundSettings {
+        muted: true,
+        master_volume: 0.8,
+        ..Default::default()
+    };
+    settings.volumes.insert(Category::Notification, 0.3);
+    let json = serde_json::to_string(&settings).unwrap();
+    assert_eq!(
+        json,
+        r#"{"muted":true,"master_volume":0.8,"volumes":{"notification":0.3}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<SoundSettings>(&json).unwrap(),
+        settings
+    );
+}
+
+#[test]
+fn missing_settings_use_the_defaults() {
+    let settings: SoundSettings =
+        serde_json::from_str(r#"{"muted":true}"#).unwrap();
+    assert!(settings.muted);
+    assert_eq!(settings.master_volume, 1.0);
+    assert_eq!(settings.volume(Category::Typing), 1.0);
+}
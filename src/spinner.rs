@@ -22,6 +22,12 @@ impl<MSG> Spinner<MSG> {
     }
 }
 
+impl<MSG> Default for Spinner<MSG> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<MSG> View<MSG> for Spinner<MSG> {
     fn view(&self) -> Node<MSG> {
         let class_ns = |class_names| {
@@ -0,0 +1,59 @@
+//! Render the widgets into static html outside of the browser,
+//! which can be used to pre-render pages or snapshot test the markup.
+use crate::{
+    common, icon, AnimateList, Button, Frame, NavHeader, Spinner, TextInput,
+    Theme,
+};
+use sauron::{Node, Render};
+
+/// the stylesheets of all the widgets which has static styles.
+/// The `Image` style depends on its properties, append `Image::style` for each image used.
+pub fn stylesheet(theme: &Theme) -> String {
+    [
+        common::style(theme),
+        icon::style(theme),
+        NavHeader::<()>::style(theme),
+        Frame::<()>::style(theme),
+        Button::<()>::style(theme),
+        AnimateList::<()>::style(theme),
+        Spinner::<()>::style(theme),
+        TextInput::<()>::style(theme),
+    ]
+    .join("\n")
+}
+
+/// render the node into an html string
+pub fn render_to_string<MSG>(node: &Node<MSG>) -> String {
+    node.render_to_string()
+}
+
+/// render a complete html document with the css injected in the head
+/// and the node as the content of the body
+pub fn render_page<MSG>(title: &str, css: &str, body: &Node<MSG>) -> String {
+    format!(
+        "<!DOCTYPE html>\n\
+        <html>\n\
+        <head>\n\
+        <meta content=\"text/html;charset=utf-8\" http-equiv=\"Content-Type\"/>\n\
+        <title>{}</title>\n\
+        <style type=\"text/css\">\n{}\n</style>\n\
+        </head>\n\
+        <body>\n{}\n</body>\n\
+        </html>\n",
+        escape_html(title),
+        css,
+        render_to_string(body)
+    )
+}
+
+pub(crate) fn escape_html(txt: &str) -> String {
+    txt.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod snapshot_tests;
@@ -0,0 +1,247 @@
+//! Compares the rendered markup and the generated css of the widgets
+//! against the fixtures committed in `src/ssr/snapshots`.
+//!
+//! Run the tests with `UPDATE_SNAPSHOTS=1 cargo test` to write the fixtures
+//! after an intended change, then review the diff before committing it.
+use super::*;
+use crate::{
+    common, AnimateList, ChippedShape, Corner, Icon, Image, Paragraph,
+};
+use sauron::prelude::*;
+use std::{env, fs, path::PathBuf};
+
+/// the preset themes the styles are snapshot for
+fn themes() -> Vec<(&'static str, Theme)> {
+    vec![
+        ("bondi_blue_on_dark", Theme::bondi_blue_on_dark()),
+        ("white_on_dark", Theme::white_on_dark()),
+        ("green_on_black", Theme::green_on_black()),
+        ("black_on_white", Theme::black_on_white()),
+    ]
+}
+
+fn snapshot_path(fixture: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/ssr/snapshots")
+        .join(fixture)
+}
+
+/// compare the actual output against the fixture with this file name,
+/// the fixture is written instead when `UPDATE_SNAPSHOTS` is set.
+fn assert_snapshot(fixture: &str, actual: &str) {
+    let path = snapshot_path(fixture);
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).expect("unable to write snapshot");
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    assert!(
+        expected == actual,
+        "snapshot {} does not match, run with UPDATE_SNAPSHOTS=1 to update it\n\
+        --- expected\n{}\n--- actual\n{}",
+        fixture,
+        expected,
+        actual
+    );
+}
+
+/// snapshot the markup of the node
+fn assert_markup<MSG>(widget: &str, node: &Node<MSG>) {
+    assert_snapshot(&format!("{}.html", widget), &render_to_string(node));
+}
+
+/// snapshot the style of the widget for each of the preset themes
+fn assert_styles(widget: &str, widget_style: impl Fn(&Theme) -> String) {
+    for (theme_name, theme) in themes() {
+        assert_snapshot(
+            &format!("{}.{}.css", widget, theme_name),
+            &widget_style(&theme),
+        );
+    }
+}
+
+#[test]
+fn button_markup() {
+    assert_markup("button", &Button::<()>::with_label("Launch").view());
+    assert_markup(
+        "button_error",
+        &Button::<()>::with_label("Abort").error().view(),
+    );
+    assert_markup(
+        "button_sized",
+        &Button::<()>::with_label("Wide")
+            .width(300)
+            .height(60)
+            .view(),
+    );
+}
+
+#[test]
+fn chipped_button_markup() {
+    assert_markup(
+        "button_chipped",
+        &Button::<()>::with_label("Deploy").chipped().view(),
+    );
+    assert_markup(
+        "button_chipped_corners",
+        &Button::<()>::with_label("Deploy")
+            .chipped_with(
+                ChippedShape::new().chip(Corner::TopLeft, 10.0).chip_sized(
+                    Corner::BottomRight,
+                    30.0,
+                    15.0,
+                ),
+            )
+            .width(240)
+            .height(50)
+            .view(),
+    );
+}
+
+#[test]
+fn icon_button_markup() {
+    assert_markup(
+        "button_icons",
+        &Button::<()>::with_label("Next")
+            .with_leading_icon(Icon::Play.view())
+            .with_trailing_icon(Icon::ArrowRight.view())
+            .view(),
+    );
+    assert_markup(
+        "button_icon_only",
+        &Button::<()>::icon_only("Close", Icon::Close.view()).view(),
+    );
+}
+
+#[test]
+fn button_styles() {
+    assert_styles("button", Button::<()>::style);
+}
+
+#[test]
+fn text_input_markup() {
+    assert_markup(
+        "text_input",
+        &TextInput::<()>::with_placeholder("Callsign").view(),
+    );
+    assert_markup(
+        "text_input_error",
+        &TextInput::<()>::with_placeholder("Sector")
+            .with_value("7g")
+            .error()
+            .with_message("the sector is a number")
+            .view(),
+    );
+}
+
+#[test]
+fn text_input_styles() {
+    assert_styles("text_input", TextInput::<()>::style);
+}
+
+#[test]
+fn frame_markup() {
+    assert_markup(
+        "frame",
+        &Frame::<()>::with_content(text("Framed content")).view(),
+    );
+}
+
+#[test]
+fn frame_with_animation_markup() {
+    assert_markup(
+        "frame_grip",
+        &Frame::<()>::with_content(text("Gripped content"))
+            .with_animation(crate::FrameAnimation::Grip)
+            .with_duration(400.0)
+            .expand_corners(false)
+            .view(),
+    );
+}
+
+#[test]
+fn chipped_frame_markup() {
+    assert_markup(
+        "frame_chipped",
+        &Frame::<()>::with_content(text("Chipped content"))
+            .with_chipped(
+                ChippedShape::new()
+                    .chip(Corner::TopRight, 12.0)
+                    .chip(Corner::BottomLeft, 12.0),
+            )
+            .view(),
+    );
+}
+
+#[test]
+fn frame_styles() {
+    assert_styles("frame", Frame::<()>::style);
+}
+
+#[test]
+fn nav_header_markup() {
+    assert_markup(
+        "nav_header",
+        &NavHeader::<()>::with_content("Header").view(),
+    );
+}
+
+#[test]
+fn nav_header_styles() {
+    assert_styles("nav_header", NavHeader::<()>::style);
+}
+
+#[test]
+fn spinner_markup() {
+    assert_markup("spinner", &Spinner::<()>::new().view());
+}
+
+#[test]
+fn spinner_styles() {
+    assert_styles("spinner", Spinner::<()>::style);
+}
+
+#[test]
+fn image_markup() {
+    assert_markup("image", &Image::<()>::new("img/space.jpg").view());
+}
+
+#[test]
+fn image_styles() {
+    let image = Image::<()>::new("img/space.jpg");
+    assert_styles("image", |theme| image.style(theme));
+}
+
+#[test]
+fn animate_list_markup() {
+    let list = AnimateList::<()>::with_content(p([], [text("typed text")]));
+    assert_markup("animate_list", &list.view());
+    let paragraph = Paragraph::<()>::new_with_markdown("a paragraph");
+    assert_markup("paragraph", &paragraph.view());
+}
+
+#[test]
+fn animate_list_styles() {
+    assert_styles("animate_list", AnimateList::<()>::style);
+}
+
+#[test]
+fn common_styles() {
+    assert_styles("common", common::style);
+}
+
+#[test]
+fn css_variables_styles() {
+    assert_styles("css_variables", |theme| {
+        format!(
+            "{}{}",
+            theme.css_variables(),
+            stylesheet(&theme.with_css_variables())
+        )
+    });
+}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::{AnimateList, Image, Paragraph};
+use sauron::prelude::*;
+
+#[test]
+fn render_button() {
+    let button = Button::<()>::with_label("Launch");
+    let html = render_to_string(&button.view());
+    assert!(html.contains("fui-button"), "{}", html);
+    assert!(html.contains("Launch"), "{}", html);
+}
+
+#[test]
+fn render_frame() {
+    let frame = Frame::<()>::with_content(text("Framed content"));
+    let html = render_to_string(&frame.view());
+    assert!(html.contains("fui-frame__corner"), "{}", html);
+    assert!(html.contains("Framed content"), "{}", html);
+}
+
+#[test]
+fn render_nav_header() {
+    let header = NavHeader::<()>::with_content("Header");
+    let html = render_to_string(&header.view());
+    assert!(html.starts_with("<header"), "{}", html);
+    assert!(html.contains("Header"), "{}", html);
+}
+
+#[test]
+fn render_spinner() {
+    let html = render_to_string(&Spinner::<()>::new().view());
+    assert!(html.contains("fui-spinner__circle1"), "{}", html);
+}
+
+#[test]
+fn render_animate_list_and_paragraph() {
+    let list = AnimateList::<()>::with_content(p([], [text("typed text")]));
+    let html = render_to_string(&list.view());
+    assert!(html.contains("typed text"), "{}", html);
+
+    let paragraph = Paragraph::<()>::new_with_markdown("a paragraph");
+    let html = render_to_string(&paragraph.view());
+    assert!(html.contains("a paragraph"), "{}", html);
+}
+
+#[test]
+fn render_image() {
+    let image = Image::<()>::new("img/space.jpg");
+    let html = render_to_string(&image.view());
+    assert!(html.contains("fui-image"), "{}", html);
+    assert!(image.style(&Theme::default()).contains("img/space.jpg"));
+}
+
+#[test]
+fn render_full_page() {
+    let theme = Theme::default();
+    let css = stylesheet(&theme);
+    let content = div(
+        [class("container")],
+        [Button::<()>::with_label("Ok").view()],
+    );
+    let html = render_page("Retro <Futuristic> UI", &css, &content);
+    assert!(html.starts_with("<!DOCTYPE html>\n<html>\n<head>\n"));
+    assert!(html.contains("<title>Retro &lt;Futuristic&gt; UI</title>"));
+    assert!(html.contains(".fui-button"), "{}", html);
+    assert!(html.contains(".fui-frame"), "{}", html);
+    assert!(
+        html.contains("<body>\n<div class=\"container\">"),
+        "{}",
+        html
+    );
+}
@@ -0,0 +1,297 @@
+//! Procedurally synthesized sounds, an alternative to the mp3 clips.
+//!
+//! Each event is synthesized from a `SynthParams`: an oscillator whose frequency
+//! sweeps from the start to the end frequency, shaped by an ADSR envelope.
+//! The sounds are rendered into sample buffers in rust, so the parameters can be
+//! tested natively, while the `web_audio` player of the `synth` feature plays the
+//! buffers in the browser where rapid sounds can overlap.
+use crate::sound_theme::SoundEvent;
+use std::{collections::BTreeMap, f32::consts::PI};
+
+#[cfg(feature = "synth")]
+pub mod web_audio;
+
+/// The shape of the oscillator wave
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    Sine,
+    Square,
+    Sawtooth,
+    Triangle,
+    /// white noise, the frequency is ignored
+    Noise,
+}
+
+/// The amplitude of the sound over time, the times are in seconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    /// the time to rise from silence to the full volume
+    pub attack: f32,
+    /// the time to fall from the full volume to the sustain level
+    pub decay: f32,
+    /// the level from 0.0 to 1.0 held until the release
+    pub sustain: f32,
+    /// the time to fall from the sustain level to silence at the end of the sound
+    pub release: f32,
+}
+
+/// The parameters of a synthesized sound
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SynthParams {
+    pub waveform: Waveform,
+    /// the frequency in hz at the start of the sound
+    pub start_frequency: f32,
+    /// the frequency in hz at the end of the sound, the same as the start for a steady tone
+    pub end_frequency: f32,
+    /// the length of the sound in seconds
+    pub duration: f32,
+    pub envelope: Envelope,
+    /// the peak amplitude from 0.0 to 1.0
+    pub volume: f32,
+}
+
+/// The synthesized sound of each event
+#[derive(Debug, Clone, PartialEq)]
+pub struct SynthVoices {
+    params: BTreeMap<SoundEvent, SynthParams>,
+}
+
+impl Envelope {
+    /// the amplitude from 0.0 to 1.0 at `t` seconds of a sound lasting `duration` seconds
+    pub fn amplitude(&self, t: f32, duration: f32) -> f32 {
+        if t < 0.0 || t >= duration {
+            return 0.0;
+        }
+        let release_start = (duration - self.release).max(0.0);
+        let level = if t < self.attack {
+            t / self.attack
+        } else if t < self.attack + self.decay {
+            1.0 - (1.0 - self.sustain) * (t - self.attack) / self.decay
+        } else {
+            self.sustain
+        };
+        if t >= release_start && self.release > 0.0 {
+            level * (duration - t) / self.release
+        } else {
+            level
+        }
+        .clamp(0.0, 1.0)
+    }
+}
+
+impl SynthParams {
+    /// a short high blip for clicks
+    pub fn blip() -> Self {
+        SynthParams {
+            waveform: Waveform::Square,
+            start_frequency: 1_200.0,
+            end_frequency: 900.0,
+            duration: 0.06,
+            envelope: Envelope {
+                attack: 0.002,
+                decay: 0.02,
+                sustain: 0.4,
+                release: 0.03,
+            },
+            volume: 0.3,
+        }
+    }
+
+    /// a softer and shorter blip for hovers
+    pub fn hover_blip() -> Self {
+        SynthParams {
+            waveform: Waveform::Sine,
+            start_frequency: 1_600.0,
+            end_frequency: 1_600.0,
+            duration: 0.03,
+            volume: 0.15,
+            ..Self::blip()
+        }
+    }
+
+    /// a tick of noise for each typed character
+    pub fn typing_tick() -> Self {
+        SynthParams {
+            waveform: Waveform::Noise,
+            start_frequency: 0.0,
+            end_frequency: 0.0,
+            duration: 0.02,
+            envelope: Envelope {
+                attack: 0.001,
+                decay: 0.01,
+                sustain: 0.2,
+                release: 0.008,
+            },
+            volume: 0.2,
+        }
+    }
+
+    /// a rising sweep when frames and headers deploy
+    pub fn deploy_sweep() -> Self {
+        SynthParams {
+            waveform: Waveform::Sawtooth,
+            start_frequency: 200.0,
+            end_frequency: 1_400.0,
+            duration: 0.35,
+            envelope: Envelope {
+                attack: 0.02,
+                decay: 0.1,
+                sustain: 0.6,
+                release: 0.15,
+            },
+            volume: 0.25,
+        }
+    }
+
+    /// a low falling buzz
+    pub fn error_buzz() -> Self {
+        SynthParams {
+            waveform: Waveform::Square,
+            start_frequency: 220.0,
+            end_frequency: 110.0,
+            duration: 0.3,
+            envelope: Envelope {
+                attack: 0.005,
+                decay: 0.05,
+                sustain: 0.7,
+                release: 0.1,
+            },
+            volume: 0.3,
+        }
+    }
+
+    /// a bright rising chime
+    pub fn success_chime() -> Self {
+        SynthParams {
+            waveform: Waveform::Triangle,
+            start_frequency: 660.0,
+            end_frequency: 990.0,
+            duration: 0.25,
+            envelope: Envelope {
+                attack: 0.005,
+                decay: 0.08,
+                sustain: 0.5,
+                release: 0.12,
+            },
+            volume: 0.3,
+        }
+    }
+
+    /// a steady mid tone
+    pub fn warning_tone() -> Self {
+        SynthParams {
+            waveform: Waveform::Triangle,
+            start_frequency: 440.0,
+            end_frequency: 440.0,
+            duration: 0.25,
+            ..Self::error_buzz()
+        }
+    }
+
+    /// the default synthesized sound of the event
+    pub fn for_event(event: SoundEvent) -> Self {
+        match event {
+            SoundEvent::Click => Self::blip(),
+            SoundEvent::Hover => Self::hover_blip(),
+            SoundEvent::Deploy => Self::deploy_sweep(),
+            SoundEvent::Typing => Self::typing_tick(),
+            SoundEvent::Error => Self::error_buzz(),
+            SoundEvent::Success => Self::success_chime(),
+            SoundEvent::Warning => Self::warning_tone(),
+        }
+    }
+
+    /// the frequency at `t` seconds, swept exponentially so it is heard as a linear glide
+    pub fn frequency(&self, t: f32) -> f32 {
+        let progress = (t / self.duration).clamp(0.0, 1.0);
+        if self.start_frequency > 0.0 && self.end_frequency > 0.0 {
+            self.start_frequency
+                * (self.end_frequency / self.start_frequency).powf(progress)
+        } else {
+            self.start_frequency
+                + (self.end_frequency - self.start_frequency) * progress
+        }
+    }
+
+    /// the number of samples of the sound at the sample rate
+    pub fn sample_count(&self, sample_rate: f32) -> usize {
+        (self.duration.max(0.0) * sample_rate).round() as usize
+    }
+
+    /// render the sound into mono samples from -1.0 to 1.0
+    pub fn render(&self, sample_rate: f32) -> Vec<f32> {
+        let volume = self.volume.clamp(0.0, 1.0);
+        let mut noise = Noise::default();
+        let mut phase = 0.0_f32;
+        (0..self.sample_count(sample_rate))
+            .map(|i| {
+                let t = i as f32 / sample_rate;
+                let wave = match self.waveform {
+                    Waveform::Sine => (2.0 * PI * phase).sin(),
+                    Waveform::Square => {
+                        if phase < 0.5 {
+                            1.0
+                        } else {
+                            -1.0
+                        }
+                    }
+                    Waveform::Sawtooth => 2.0 * phase - 1.0,
+                    Waveform::Triangle => 1.0 - 4.0 * (phase - 0.5).abs(),
+                    Waveform::Noise => noise.next_sample(),
+                };
+                phase = (phase + self.frequency(t) / sample_rate).fract();
+                wave * self.envelope.amplitude(t, self.duration) * volume
+            })
+            .collect()
+    }
+}
+
+impl SynthVoices {
+    /// the synthesized sound of the event
+    pub fn get(&self, event: SoundEvent) -> SynthParams {
+        self.params
+            .get(&event)
+            .copied()
+            .unwrap_or_else(|| SynthParams::for_event(event))
+    }
+
+    /// synthesize the event with these parameters instead
+    pub fn set(&mut self, event: SoundEvent, params: SynthParams) {
+        self.params.insert(event, params);
+    }
+}
+
+impl Default for SynthVoices {
+    fn default() -> Self {
+        SynthVoices {
+            params: SoundEvent::all()
+                .into_iter()
+                .map(|event| (event, SynthParams::for_event(event)))
+                .collect(),
+        }
+    }
+}
+
+/// a deterministic white noise, so the rendered sounds are reproducible
+struct Noise(u32);
+
+impl Default for Noise {
+    fn default() -> Self {
+        Noise(0x2545_f491)
+    }
+}
+
+impl Noise {
+    /// xorshift32, mapped into -1.0 to 1.0
+    fn next_sample(&mut self) -> f32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        x as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+use super::*;
+
+const SAMPLE_RATE: f32 = 8_000.0;
+
+/// the number of times the wave crosses zero from negative to positive
+fn rising_zero_crossings(samples: &[f32]) -> usize {
+    samples
+        .windows(2)
+        .filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0)
+        .count()
+}
+
+fn peak(samples: &[f32]) -> f32 {
+    samples
+        .iter()
+        .fold(0.0, |max_amp: f32, s| max_amp.max(s.abs()))
+}
+
+#[test]
+fn renders_the_duration_at_the_sample_rate() {
+    let blip = SynthParams::blip();
+    let samples = blip.render(SAMPLE_RATE);
+    assert_eq!(samples.len(), 480);
+    assert_eq!(samples.len(), blip.sample_count(SAMPLE_RATE));
+}
+
+#[test]
+fn samples_are_within_the_volume() {
+    for event in SoundEvent::all() {
+        let params = SynthParams::for_event(event);
+        let samples = params.render(SAMPLE_RATE);
+        assert!(!samples.is_empty(), "{:?} is silent", event);
+        assert!(peak(&samples) <= params.volume, "{:?} is too loud", event);
+        assert!(peak(&samples) > 0.0, "{:?} is silent", event);
+    }
+}
+
+#[test]
+fn sound_fades_in_and_out() {
+    let samples = SynthParams::deploy_sweep().render(SAMPLE_RATE);
+    assert_eq!(samples[0], 0.0);
+    let tail = &samples[samples.len() - 8..];
+    assert!(peak(tail) < 0.01);
+}
+
+#[test]
+fn envelope_follows_attack_decay_sustain_release() {
+    let envelope = Envelope {
+        attack: 0.1,
+        decay: 0.1,
+        sustain: 0.5,
+        release: 0.2,
+    };
+    assert_eq!(envelope.amplitude(0.0, 1.0), 0.0);
+    assert_eq!(envelope.amplitude(0.05, 1.0), 0.5);
+    assert_eq!(envelope.amplitude(0.1, 1.0), 1.0);
+    assert_eq!(envelope.amplitude(0.15, 1.0), 0.75);
+    assert_eq!(envelope.amplitude(0.5, 1.0), 0.5);
+    assert!((envelope.amplitude(0.9, 1.0) - 0.25).abs() < 1e-6);
+    assert_eq!(envelope.amplitude(1.0, 1.0), 0.0);
+}
+
+#[test]
+fn deploy_sweeps_up() {
+    let sweep = SynthParams {
+        waveform: Waveform::Sine,
+        ..SynthParams::deploy_sweep()
+    };
+    assert_eq!(sweep.frequency(0.0), 200.0);
+    assert!((sweep.frequency(sweep.duration) - 1_400.0).abs() < 0.1);
+
+    let samples = sweep.render(SAMPLE_RATE);
+    let half = samples.len() / 2;
+    assert!(
+        rising_zero_crossings(&samples[half..])
+            > 2 * rising_zero_crossings(&samples[..half])
+    );
+}
+
+#[test]
+fn steady_tone_has_its_frequency() {
+    let tone = SynthParams {
+        waveform: Waveform::Sine,
+        start_frequency: 400.0,
+        end_frequency: 400.0,
+        duration: 1.0,
+        envelope: Envelope {
+            attack: 0.0,
+            decay: 0.0,
+            sustain: 1.0,
+            release: 0.0,
+        },
+        volume: 1.0,
+    };
+    let crossings = rising_zero_crossings(&tone.render(SAMPLE_RATE));
+    assert!((399..=400).contains(&crossings), "{}", crossings);
+}
+
+#[test]
+fn noise_is_reproducible() {
+    let tick = SynthParams::typing_tick();
+    assert_eq!(tick.render(SAMPLE_RATE), tick.render(SAMPLE_RATE));
+    let samples = tick.render(SAMPLE_RATE);
+    assert!(samples.iter().any(|s| *s > 0.0));
+    assert!(samples.iter().any(|s| *s < 0.0));
+}
+
+#[test]
+fn voices_can_be_customized_per_event() {
+    let mut voices = SynthVoices::default();
+    assert_eq!(voices.get(SoundEvent::Click), SynthParams::blip());
+
+    let softer = SynthParams {
+        volume: 0.1,
+        ..SynthParams::blip()
+    };
+    voices.set(SoundEvent::Click, softer);
+    assert_eq!(voices.get(SoundEvent::Click), softer);
+    assert_eq!(voices.get(SoundEvent::Error), SynthParams::error_buzz());
+}
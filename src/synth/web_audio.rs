@@ -0,0 +1,140 @@
+//! Play the synthesized sounds with the Web Audio api.
+//!
+//! The samples of each event are rendered once into an `AudioBuffer`,
+//! each play starts a new buffer source so rapid sounds overlap instead of restarting.
+//! A looping sound keeps its source, so it can be stopped.
+use super::SynthParams;
+use crate::sound_theme::SoundEvent;
+use sauron::wasm_bindgen::JsValue;
+use std::collections::BTreeMap;
+use web_sys::{
+    AudioBuffer, AudioBufferSourceNode, AudioContext, AudioScheduledSourceNode,
+};
+
+/// The audio context and the rendered buffer of each event
+#[derive(Default)]
+pub struct WebAudioSynth {
+    /// created on the first play, since it needs the browser
+    context: Option<AudioContext>,
+    buffers: BTreeMap<SoundEvent, (SynthParams, AudioBuffer)>,
+    /// the sources which are looping, stopped by their event
+    looping: BTreeMap<SoundEvent, AudioBufferSourceNode>,
+}
+
+impl WebAudioSynth {
+    fn context(&mut self) -> Option<AudioContext> {
+        if self.context.is_none() {
+            match AudioContext::new() {
+                Ok(context) => self.context = Some(context),
+                Err(e) => {
+                    log::warn!("unable to create an audio context: {:?}", e)
+                }
+            }
+        }
+        self.context.clone()
+    }
+
+    /// resume the audio context, which starts suspended until the user interacts
+    pub fn resume(&mut self) {
+        if let Some(context) = self.context() {
+            if let Err(e) = context.resume() {
+                log::warn!("unable to resume the audio context: {:?}", e);
+            }
+        }
+    }
+
+    /// the buffer of the event, rendered again when its parameters changed
+    fn buffer(
+        &mut self,
+        context: &AudioContext,
+        event: SoundEvent,
+        params: SynthParams,
+    ) -> Option<AudioBuffer> {
+        if let Some((rendered, buffer)) = self.buffers.get(&event) {
+            if *rendered == params {
+                return Some(buffer.clone());
+            }
+        }
+        let sample_rate = context.sample_rate();
+        let samples = params.render(sample_rate);
+        if samples.is_empty() {
+            return None;
+        }
+        let buffer = context
+            .create_buffer(1, samples.len() as u32, sample_rate)
+            .ok()?;
+        buffer.copy_to_channel(&samples, 0).ok()?;
+        self.buffers.insert(event, (params, buffer.clone()));
+        Some(buffer)
+    }
+
+    /// play the synthesized sound of the event at the volume
+    pub fn play(
+        &mut self,
+        event: SoundEvent,
+        params: SynthParams,
+        volume: f64,
+    ) {
+        self.start(event, params, volume, false);
+    }
+
+    /// play the synthesized sound of the event over and over until it is stopped
+    pub fn play_looped(
+        &mut self,
+        event: SoundEvent,
+        params: SynthParams,
+        volume: f64,
+    ) {
+        self.stop(event);
+        if let Some(source) = self.start(event, params, volume, true) {
+            self.looping.insert(event, source);
+        }
+    }
+
+    /// stop the looping sound of the event
+    pub fn stop(&mut self, event: SoundEvent) {
+        if let Some(source) = self.looping.remove(&event) {
+            let scheduled: &AudioScheduledSourceNode = source.as_ref();
+            if let Err(e) = scheduled.stop() {
+                log::warn!(
+                    "unable to stop the synthesized {:?}: {:?}",
+                    event,
+                    e
+                );
+            }
+        }
+    }
+
+    fn start(
+        &mut self,
+        event: SoundEvent,
+        params: SynthParams,
+        volume: f64,
+        looped: bool,
+    ) -> Option<AudioBufferSourceNode> {
+        let context = self.context()?;
+        let buffer = self.buffer(&context, event, params)?;
+        let started = (|| {
+            let source = context.create_buffer_source()?;
+            source.set_buffer(Some(&buffer));
+            source.set_loop(looped);
+            let gain = context.create_gain()?;
+            gain.gain().set_value(volume as f32);
+            source.connect_with_audio_node(&gain)?;
+            gain.connect_with_audio_node(&context.destination())?;
+            source.start()?;
+            Ok(source)
+        })();
+        match started {
+            Ok(source) => Some(source),
+            Err::<_, JsValue>(e) => {
+                log::warn!(
+                    "unable to play the synthesized {:?}: {:?}",
+                    event,
+                    e
+                );
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use crate::{
+    decorated_container::{DecoratedContainer, Dimension},
+    sound_theme::SoundEvent,
+};
+use sauron::jss_ns;
+use sauron::wasm_bindgen::JsCast;
+use sauron::{
+    dom::Callback,
+    html::attributes,
+    html::{div, input, text},
+    prelude::*,
+    Node,
+};
+
+const COMPONENT_NAME: &str = "fui-text-input";
+
+/// decides the validation state of a value
+type Validator = Box<dyn Fn(&str) -> Option<Validation>>;
+
+#[derive(Clone, Debug)]
+pub enum Msg {
+    /// the value is edited
+    Input(String),
+    /// a key is pressed while the input is focused, enter submits the value
+    KeyDown(String),
+    Focus,
+    Blur,
+    HoverIn,
+    HoverOut,
+    Mounted(MountEvent),
+}
+
+/// The validation state of the input, colored with the pallete of the theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Validation {
+    Error,
+    Success,
+    Info,
+    Warning,
+}
+
+/// A text input with the borders, corners and hover effect of the `Button`
+pub struct TextInput<PMSG> {
+    options: Options,
+    value: String,
+    placeholder: String,
+    validation: Option<Validation>,
+    /// the text shown below the input, such as the reason it is invalid
+    message: Option<String>,
+    /// validates the value after each edit
+    validator: Option<Validator>,
+    focused: bool,
+    hover: bool,
+    change_listeners: Vec<Callback<String, PMSG>>,
+    submit_listeners: Vec<Callback<String, PMSG>>,
+    pub width: Option<usize>,
+    component_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Options {
+    /// play the typing sound as the value is edited,
+    /// and the sound of the validation when submitted
+    pub sound: bool,
+    /// has corners
+    pub has_corners: bool,
+    /// the input has borders
+    pub has_borders: bool,
+    /// enable/disable hover effect
+    pub has_hover: bool,
+    /// expand corners when hovered or focused
+    pub expand_corners: bool,
+    /// the borders glow when the input is focused
+    pub focus_glow: bool,
+    /// the input is disabled
+    pub disabled: bool,
+}
+
+impl Validation {
+    fn class_name(&self) -> &'static str {
+        match self {
+            Validation::Error => "error",
+            Validation::Success => "success",
+            Validation::Info => "info",
+            Validation::Warning => "warning",
+        }
+    }
+
+    /// the sound played when a value with this validation is submitted
+    fn sound_event(&self) -> SoundEvent {
+        match self {
+            Validation::Error => SoundEvent::Error,
+            Validation::Success => SoundEvent::Success,
+            Validation::Info => SoundEvent::Click,
+            Validation::Warning => SoundEvent::Warning,
+        }
+    }
+}
+
+impl<PMSG> std::fmt::Debug for TextInput<PMSG> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextInput")
+            .field("options", &self.options)
+            .field("value", &self.value)
+            .field("placeholder", &self.placeholder)
+            .field("validation", &self.validation)
+            .field("message", &self.message)
+            .field("focused", &self.focused)
+            .field("hover", &self.hover)
+            .field("width", &self.width)
+            .field("component_id", &self.component_id)
+            .finish()
+    }
+}
+
+impl<PMSG> TextInput<PMSG>
+where
+    PMSG: 'static,
+{
+    /// an empty input showing the placeholder
+    pub fn with_placeholder(placeholder: &str) -> Self {
+        TextInput {
+            options: Options::regular(),
+            value: String::new(),
+            placeholder: placeholder.to_string(),
+            validation: None,
+            message: None,
+            validator: None,
+            focused: false,
+            hover: false,
+            change_listeners: vec![],
+            submit_listeners: vec![],
+            width: None,
+            component_id: None,
+        }
+    }
+
+    /// the current value of the input
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// the current validation state of the input
+    pub fn validation(&self) -> Option<Validation> {
+        self.validation
+    }
+
+    /// replace the value, such as when the bound value is changed by the application
+    pub fn set_value(&mut self, new_value: impl ToString) {
+        self.value = new_value.to_string();
+        self.validate();
+    }
+
+    /// set the validation state and the message shown below the input
+    pub fn set_validation(
+        &mut self,
+        validation: Option<Validation>,
+        message: Option<String>,
+    ) {
+        self.validation = validation;
+        self.message = message;
+    }
+
+    /// run the validator against the current value,
+    /// the message of the previous validation state is cleared when the state changes
+    fn validate(&mut self) {
+        if let Some(validator) = &self.validator {
+            let validation = validator(&self.value);
+            if validation != self.validation {
+                self.message = None;
+            }
+            self.validation = validation;
+        }
+    }
+
+    /// the sound played when the value is submitted, which depends on its validation
+    fn submit_sound(&self) -> SoundEvent {
+        self.validation
+            .as_ref()
+            .map(Validation::sound_event)
+            .unwrap_or(SoundEvent::Click)
+    }
+
+    fn view_input(&self) -> Node<Msg> {
+        let class_ns = |class_names| {
+            attributes::class_namespaced(COMPONENT_NAME, class_names)
+        };
+        input(
+            [
+                class_ns("input"),
+                r#type("text"),
+                value(&self.value),
+                placeholder(&self.placeholder),
+                disabled(self.options.disabled),
+                if let Some(width) = self.width {
+                    style! {width: px(width)}
+                } else {
+                    empty_attr()
+                },
+                if self.validation == Some(Validation::Error) {
+                    attr("aria-invalid", "true")
+                } else {
+                    empty_attr()
+                },
+                on_input(|event| Msg::Input(event.value)),
+                on_keydown(|event| Msg::KeyDown(event.key())),
+                on_focus(|_| Msg::Focus),
+                on_blur(|_| Msg::Blur),
+            ],
+            [],
+        )
+    }
+}
+
+impl<PMSG> Component<Msg, PMSG> for TextInput<PMSG>
+where
+    PMSG: 'static,
+{
+    fn get_component_id(&self) -> Option<&String> {
+        self.component_id.as_ref()
+    }
+
+    fn update(&mut self, msg: Msg) -> Effects<Msg, PMSG> {
+        match msg {
+            Msg::Input(input_value) => {
+                if self.options.sound {
+                    SoundEvent::Typing.play();
+                }
+                self.value = input_value;
+                self.validate();
+                let pmsg_list = self
+                    .change_listeners
+                    .iter()
+                    .map(|listener| listener.emit(self.value.clone()));
+                Effects::with_external(pmsg_list)
+            }
+            Msg::KeyDown(key) => {
+                if key != "Enter" || self.options.disabled {
+                    return Effects::none();
+                }
+                if self.options.sound {
+                    self.submit_sound().play();
+                }
+                let pmsg_list = self
+                    .submit_listeners
+                    .iter()
+                    .map(|listener| listener.emit(self.value.clone()));
+                Effects::with_external(pmsg_list)
+            }
+            Msg::Focus => {
+                self.focused = true;
+                Effects::none()
+            }
+            Msg::Blur => {
+                self.focused = false;
+                Effects::none()
+            }
+            Msg::HoverIn => {
+                self.hover = true;
+                Effects::none()
+            }
+            Msg::HoverOut => {
+                self.hover = false;
+                Effects::none()
+            }
+            Msg::Mounted(me) => {
+                let target_node = me.target_node;
+                let target_elm: &web_sys::Element =
+                    target_node.dyn_ref().expect("must cast");
+                if let Some(vdom_id) = target_elm.get_attribute("data-vdom-id")
+                {
+                    self.component_id = Some(vdom_id);
+                }
+                Effects::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        let class_ns = |class_names| {
+            attributes::class_namespaced(COMPONENT_NAME, class_names)
+        };
+
+        let classes_ns_flag = |class_name_flags| {
+            attributes::classes_flag_namespaced(
+                COMPONENT_NAME,
+                class_name_flags,
+            )
+        };
+
+        DecoratedContainer::new(COMPONENT_NAME)
+            .has_borders(self.options.has_borders)
+            .has_corners(self.options.has_corners)
+            .has_hover(self.options.has_hover)
+            .expand_corners(self.options.expand_corners)
+            // the corners stay expanded while typing
+            .hovered(self.hover || self.focused)
+            .view(
+                [
+                    classes_ns_flag([
+                        ("focused", self.focused),
+                        ("focus_glow", self.options.focus_glow),
+                        ("disabled", self.options.disabled),
+                    ]),
+                    if let Some(ref validation) = self.validation {
+                        class_ns(validation.class_name())
+                    } else {
+                        empty_attr()
+                    },
+                    on_mouseover(|_| Msg::HoverIn),
+                    on_mouseout(|_| Msg::HoverOut),
+                    on_mount(Msg::Mounted),
+                ],
+                [
+                    div([class_ns("input_wrap")], [self.view_input()]),
+                    if let Some(ref message) = self.message {
+                        div([class_ns("message")], [text(message)])
+                    } else {
+                        comment("message")
+                    },
+                ],
+            )
+    }
+}
+
+impl<PMSG> TextInput<PMSG>
+where
+    PMSG: 'static,
+{
+    pub fn with_options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// bind the input to this value
+    pub fn with_value(mut self, initial_value: impl ToString) -> Self {
+        self.set_value(initial_value);
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn error(mut self) -> Self {
+        self.validation = Some(Validation::Error);
+        self
+    }
+
+    pub fn success(mut self) -> Self {
+        self.validation = Some(Validation::Success);
+        self
+    }
+
+    pub fn info(mut self) -> Self {
+        self.validation = Some(Validation::Info);
+        self
+    }
+
+    pub fn warning(mut self) -> Self {
+        self.validation = Some(Validation::Warning);
+        self
+    }
+
+    /// show this message below the input
+    pub fn with_message(mut self, message: impl ToString) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// validate the value after each edit, `None` clears the validation state
+    pub fn with_validator<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) -> Option<Validation> + 'static,
+    {
+        self.validator = Some(Box::new(f));
+        self.validate();
+        self
+    }
+
+    /// the listener is called with the value after each edit
+    pub fn add_change_listener<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String) -> PMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.change_listeners.push(cb);
+        self
+    }
+
+    /// the listener is called with the value when enter is pressed
+    pub fn add_submit_listener<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String) -> PMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.submit_listeners.push(cb);
+        self
+    }
+
+    pub fn style(theme: &crate::Theme) -> String {
+        let base = &theme.controls;
+        let transition_time_ms = 250; //transition time for most effects on the input
+        let decorations = Dimension {
+            border_width: 1,
+            corner_width: 2,
+            corner_length: 8,
+            corner_expand_distance: 6,
+            corner_shadow: false,
+            has_hover: true,
+            has_pallete: true,
+        };
+        let glow =
+            |glow_color: String| format!("{} {}", px([0, 0, 10]), glow_color);
+
+        let input_style = jss_ns! {COMPONENT_NAME,
+
+            // the ROOT component style
+            ".": {
+                display: "inline-block",
+                padding: px(1),
+                position: "relative",
+                margin: px([10, 10]),
+            },
+
+            ".input_wrap": {
+                background_color: base.content_background_color.clone(),
+                z_index: 3,
+                display: "block",
+                position: "relative",
+            },
+
+            // The actual input
+            ".input": {
+                color: base.button_text_color.clone(),
+                font_family: theme.secondary_font.clone(),
+                font_size: px(15.75),
+                margin: 0,
+                border: "none",
+                outline: "none",
+                display: "block",
+                box_sizing: "border-box",
+                padding: px([10, 20]),
+                background_color: base.content_background_color.clone(),
+                caret_color: base.highlight_color.clone(),
+                line_height: 1,
+                transition: format!("all {}ms ease-out", transition_time_ms),
+            },
+
+            ".input::placeholder": {
+                color: base.button_text_color.clone(),
+                opacity: 0.5,
+            },
+
+            ".disabled .input": {
+                cursor: "not-allowed",
+                opacity: 0.5,
+            },
+
+            // the borders glow while the input is focused
+            ".focus_glow.focused .border": {
+                box_shadow: glow(base.hover_shadow.clone()),
+            },
+
+            ".focus_glow.focused.error .border": {
+                box_shadow: glow(theme.error_color()),
+            },
+
+            ".focus_glow.focused.success .border": {
+                box_shadow: glow(theme.success_color()),
+            },
+
+            ".focus_glow.focused.info .border": {
+                box_shadow: glow(theme.info_color()),
+            },
+
+            ".focus_glow.focused.warning .border": {
+                box_shadow: glow(theme.warning_color()),
+            },
+
+            ".error .input": {
+                caret_color: theme.error_color(),
+            },
+
+            ".success .input": {
+                caret_color: theme.success_color(),
+            },
+
+            ".info .input": {
+                caret_color: theme.info_color(),
+            },
+
+            ".warning .input": {
+                caret_color: theme.warning_color(),
+            },
+
+            // the message below the input, colored with the validation
+            ".message": {
+                position: "absolute",
+                top: percent(100),
+                left: 0,
+                margin_top: px(6),
+                font_size: px(12),
+                color: base.button_text_color.clone(),
+                white_space: "nowrap",
+            },
+
+            ".error .message": {
+                color: theme.error_color(),
+            },
+
+            ".success .message": {
+                color: theme.success_color(),
+            },
+
+            ".info .message": {
+                color: theme.info_color(),
+            },
+
+            ".warning .message": {
+                color: theme.warning_color(),
+            },
+        };
+
+        format!(
+            "{}{}",
+            decorations.style(COMPONENT_NAME, theme),
+            input_style
+        )
+    }
+}
+
+impl Options {
+    /// the futuristic input with sound
+    pub fn regular() -> Self {
+        Options {
+            sound: true,
+            has_corners: true,
+            has_borders: true,
+            has_hover: true,
+            expand_corners: true,
+            focus_glow: true,
+            disabled: false,
+        }
+    }
+
+    /// just like regular but muted
+    pub fn muted() -> Self {
+        Options {
+            sound: false,
+            ..Self::regular()
+        }
+    }
+
+    /// only the borders, no corners, hover or glow
+    pub fn simple() -> Self {
+        Options {
+            sound: true,
+            has_corners: false,
+            has_borders: true,
+            has_hover: false,
+            expand_corners: false,
+            focus_glow: false,
+            disabled: false,
+        }
+    }
+
+    /// does not accept input
+    pub fn disabled() -> Self {
+        Options {
+            sound: false,
+            disabled: true,
+            ..Self::simple()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
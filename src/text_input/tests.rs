@@ -0,0 +1,106 @@
+use super::*;
+
+#[test]
+fn value_is_bound_to_the_input() {
+    let input = TextInput::<()>::with_placeholder("Callsign").with_value("Ace");
+    let html = crate::ssr::render_to_string(&input.view());
+    assert!(html.contains("value=\"Ace\""));
+    assert!(html.contains("placeholder=\"Callsign\""));
+}
+
+#[test]
+fn edits_notify_the_change_listeners() {
+    let mut input = TextInput::with_placeholder("Callsign")
+        .add_change_listener(|new_value| new_value.len());
+    let effects = input.update(Msg::Input("Maverick".to_string()));
+    assert_eq!(input.value(), "Maverick");
+    assert_eq!(effects.external, vec![8]);
+}
+
+#[test]
+fn enter_submits_the_value() {
+    let mut input = TextInput::with_placeholder("Callsign")
+        .with_value("Goose")
+        .add_submit_listener(|submitted| submitted);
+    assert!(input
+        .update(Msg::KeyDown("a".to_string()))
+        .external
+        .is_empty());
+    let effects = input.update(Msg::KeyDown("Enter".to_string()));
+    assert_eq!(effects.external, vec!["Goose".to_string()]);
+}
+
+#[test]
+fn disabled_input_does_not_submit() {
+    let mut input = TextInput::with_placeholder("Callsign")
+        .with_options(Options::disabled())
+        .add_submit_listener(|submitted| submitted);
+    let effects = input.update(Msg::KeyDown("Enter".to_string()));
+    assert!(effects.external.is_empty());
+}
+
+#[test]
+fn validator_runs_after_each_edit() {
+    let mut input =
+        TextInput::<()>::with_placeholder("Sector").with_validator(|sector| {
+            if sector.chars().all(|c| c.is_ascii_digit()) {
+                None
+            } else {
+                Some(Validation::Error)
+            }
+        });
+    assert_eq!(input.validation(), None);
+    input.update(Msg::Input("7g".to_string()));
+    assert_eq!(input.validation(), Some(Validation::Error));
+    assert_eq!(input.submit_sound(), SoundEvent::Error);
+    let html = crate::ssr::render_to_string(&input.view());
+    assert!(html.contains("fui-text-input__error"));
+    assert!(html.contains("aria-invalid=\"true\""));
+
+    input.update(Msg::Input("7".to_string()));
+    assert_eq!(input.validation(), None);
+    assert_eq!(input.submit_sound(), SoundEvent::Click);
+}
+
+#[test]
+fn validator_clears_the_message_of_a_changed_state() {
+    let mut input = TextInput::<()>::with_placeholder("Callsign")
+        .with_validator(|callsign| {
+            if callsign.is_empty() {
+                None
+            } else {
+                Some(Validation::Success)
+            }
+        });
+    input.update(Msg::Input("Iceman".to_string()));
+    input.set_validation(
+        Some(Validation::Error),
+        Some("callsign is taken".to_string()),
+    );
+    let html = crate::ssr::render_to_string(&input.view());
+    assert!(html.contains("callsign is taken"));
+
+    input.update(Msg::Input("Icemen".to_string()));
+    assert_eq!(input.validation(), Some(Validation::Success));
+    let html = crate::ssr::render_to_string(&input.view());
+    assert!(!html.contains("callsign is taken"));
+
+    input.set_validation(Some(Validation::Success), Some("ok".to_string()));
+    input.update(Msg::Input("Viper".to_string()));
+    assert_eq!(input.validation(), Some(Validation::Success));
+    let html = crate::ssr::render_to_string(&input.view());
+    assert!(html.contains(">ok</div>"), "{}", html);
+}
+
+#[test]
+fn focus_keeps_the_corners_expanded() {
+    let mut input = TextInput::<()>::with_placeholder("Callsign");
+    input.update(Msg::Focus);
+    let html = crate::ssr::render_to_string(&input.view());
+    assert!(html.contains("fui-text-input__focused"));
+    assert!(html.contains("fui-text-input__hovered"));
+
+    input.update(Msg::Blur);
+    let html = crate::ssr::render_to_string(&input.view());
+    assert!(!html.contains("fui-text-input__focused"));
+}
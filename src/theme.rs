@@ -1,7 +1,24 @@
 use css_color::ParseColorError;
-use css_colors::{percent, rgba, Color, RGBA};
+use css_colors::{deg, percent, rgba, Color, Ratio, HSLA, RGBA};
+use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Debug)]
+pub use contrast::{contrast_ratio, ContrastCheck, ContrastLevel, ThemeReport};
+pub use error::ThemeError;
+pub use registry::ThemeRegistry;
+pub use scheme::ColorScheme;
+pub use variables::VARIABLE_PREFIX;
+
+mod contrast;
+mod error;
+mod registry;
+mod scheme;
+mod variables;
+
+/// The theme can be saved and loaded as json (or toml with the `toml` feature).
+/// Fields which are missing in the file are taken from `Theme::default()`,
+/// so a theme file can override only the computed fields it cares about.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Theme {
     pub primary_color: String,    // used in container
     pub secondary_color: String,  // used in container
@@ -16,18 +33,46 @@ pub struct Theme {
 
 /// Issue how to derive pallet from primary?
 /// Maybe mix the pallet color with the primary color
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Pallete {
     /// color for error, default is red
+    #[serde(with = "css_rgba")]
     pub error: RGBA,
     /// color for success, default is green
+    #[serde(with = "css_rgba")]
     pub success: RGBA,
     /// color for info, default is blue
+    #[serde(with = "css_rgba")]
     pub info: RGBA,
     /// color for warning, default is yellow
+    #[serde(with = "css_rgba")]
     pub warning: RGBA,
 }
 
+impl Pallete {
+    /// create a pallete from colors that can be parse,
+    /// the colors which are not specified will use the default pallete color.
+    pub fn from_str(
+        error: Option<&str>,
+        success: Option<&str>,
+        info: Option<&str>,
+        warning: Option<&str>,
+    ) -> Result<Self, ThemeError> {
+        let default = Self::default();
+        let parse = |color: Option<&str>, default: RGBA| match color {
+            Some(color) => parse_color(color),
+            None => Ok(default),
+        };
+        Ok(Self {
+            error: parse(error, default.error)?,
+            success: parse(success, default.success)?,
+            info: parse(info, default.info)?,
+            warning: parse(warning, default.warning)?,
+        })
+    }
+}
+
 impl Default for Pallete {
     fn default() -> Self {
         Self {
@@ -41,7 +86,8 @@ impl Default for Pallete {
 
 /// colors to controls
 /// such as buttons, navigation links, frames
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Controls {
     pub hover_color: String,
     pub hover_shadow: String,
@@ -59,47 +105,101 @@ pub struct Controls {
     pub link_color: String,
 }
 
+impl Default for Controls {
+    fn default() -> Self {
+        Theme::default().controls
+    }
+}
+
 impl Theme {
+    /// load a theme from a json string
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// save the theme to a json string
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("must serialize")
+    }
+
+    /// load a theme from a toml string
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    /// save the theme to a toml string
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("must serialize")
+    }
+
     /// create a them from color that can be parse
-    #[allow(unused)]
     pub fn from_str(
         primary: &str,
         background: &str,
-    ) -> Result<Self, ParseColorError> {
-        let primary = hex_to_real_rgba(primary);
-        let background = hex_to_real_rgba(background);
+    ) -> Result<Self, ThemeError> {
+        Self::from_str_with_pallete(primary, background, Pallete::default())
+    }
+
+    /// create a theme from colors that can be parse, using a custom pallete
+    pub fn from_str_with_pallete(
+        primary: &str,
+        background: &str,
+        pallete: Pallete,
+    ) -> Result<Self, ThemeError> {
+        let primary = parse_color(primary);
+        let background = parse_color(background);
         log::debug!("parsing primary: {:?}", primary);
         log::debug!("parsing background: {:?}", background);
-        Ok(Self::calculate_theme(
-            primary?,
-            background?,
-            Pallete::default(),
-        ))
+        Ok(Self::calculate_theme(primary?, background?, pallete))
     }
-    // base theme using a bluish base color #029dbb
-    #[allow(unused)]
-    fn bondi_blue_on_dark() -> Self {
+
+    /// create a theme from the url hash in the form of
+    /// `#/primary/background[/error/success/info/warning]`.
+    /// The pallete colors are optional, an empty segment uses the default pallete color.
+    /// The `#` of hex colors can be escaped as `%23`.
+    pub fn from_url_hash(hash: &str) -> Result<Self, ThemeError> {
+        let hash = hash.trim_start_matches('#').trim_start_matches('/');
+        let segments: Vec<String> = hash
+            .split('/')
+            .map(|segment| segment.trim().replace("%23", "#"))
+            .collect();
+        let segment = |index: usize| {
+            segments
+                .get(index)
+                .map(String::as_str)
+                .filter(|segment| !segment.is_empty())
+        };
+        let primary =
+            segment(0).ok_or(ThemeError::MissingSegment("primary"))?;
+        let background =
+            segment(1).ok_or(ThemeError::MissingSegment("background"))?;
+        let pallete =
+            Pallete::from_str(segment(2), segment(3), segment(4), segment(5))?;
+        Self::from_str_with_pallete(primary, background, pallete)
+    }
+
+    /// base theme using a bluish base color #029dbb
+    pub fn bondi_blue_on_dark() -> Self {
         let primary = rgba(2, 157, 187, 1.0); // main theme
         let background = rgba(0, 0, 0, 1.0);
         Self::calculate_theme(primary, background, Pallete::default())
     }
 
-    #[allow(unused)]
-    fn white_on_dark() -> Self {
+    pub fn white_on_dark() -> Self {
         let primary = rgba(255, 255, 255, 1.0);
         let background = rgba(0, 0, 0, 1.0);
         Self::calculate_theme(primary, background, Pallete::default())
     }
 
-    #[allow(unused)]
-    fn green_on_black() -> Self {
+    pub fn green_on_black() -> Self {
         let primary = rgba(0, 255, 0, 1.0);
         let background = rgba(0, 0, 0, 1.0);
         Self::calculate_theme(primary, background, Pallete::default())
     }
 
-    #[allow(unused)]
-    fn black_on_white() -> Self {
+    pub fn black_on_white() -> Self {
         Self::calculate_theme(
             rgba(0, 0, 0, 1.0),
             rgba(255, 255, 255, 1.0),
@@ -107,6 +207,32 @@ impl Theme {
         )
     }
 
+    /// calculate the theme, then nudge the text colors which did not meet
+    /// the contrast ratio of the level.
+    pub fn calculate_theme_with_contrast(
+        foreground: RGBA,
+        background: RGBA,
+        pallete: Pallete,
+        level: ContrastLevel,
+    ) -> (Self, ThemeReport) {
+        let mut theme = Self::calculate_theme(foreground, background, pallete);
+        let report = theme.ensure_contrast(level);
+        (theme, report)
+    }
+
+    /// reject this theme if the text colors did not meet the contrast ratio of the level
+    pub fn validate_contrast(
+        self,
+        level: ContrastLevel,
+    ) -> Result<Self, ThemeError> {
+        let report = self.check_contrast(level);
+        if report.passes() {
+            Ok(self)
+        } else {
+            Err(ThemeError::LowContrast(report))
+        }
+    }
+
     /// light: if background is light and foreground is dark
     pub fn calculate_theme(
         foreground: RGBA,
@@ -127,21 +253,21 @@ impl Theme {
         };
 
         let secondary = if light {
-            primary.darken(percent(20))
+            darken(primary, percent(20))
         } else {
-            primary.lighten(percent(20))
+            lighten(primary, percent(20))
         };
 
         let text_colors = if light {
-            primary.darken(percent(40))
+            darken(primary, percent(40))
         } else {
-            primary.lighten(percent(40))
+            lighten(primary, percent(40))
         };
 
         let background_color = if light {
-            background.lighten(percent(60))
+            lighten(background, percent(60))
         } else {
-            primary.darken(percent(60))
+            darken(primary, percent(60))
         };
 
         let accent_shadow = if light {
@@ -248,17 +374,101 @@ impl Default for Theme {
 
 /// convert from color to colors version
 fn convert_to_real_rgba(color: css_color::Rgba) -> RGBA {
-    let red = (color.red * 255.0) as u8;
-    let green = (color.green * 255.0) as u8;
-    let blue = (color.blue * 255.0) as u8;
+    let red = (color.red * 255.0).round() as u8;
+    let green = (color.green * 255.0).round() as u8;
+    let blue = (color.blue * 255.0).round() as u8;
     rgba(red, green, blue, color.alpha)
 }
 
+/// `RGBA::lighten` of css-colors, without its panic on fully saturated light colors
+fn lighten(color: RGBA, amount: Ratio) -> RGBA {
+    to_hsla(color).lighten(amount).to_rgba()
+}
+
+/// `RGBA::darken` of css-colors, without its panic on fully saturated light colors
+fn darken(color: RGBA, amount: Ratio) -> RGBA {
+    to_hsla(color).darken(amount).to_rgba()
+}
+
+/// the same conversion as `RGBA::to_hsla` of css-colors, except the saturation is clamped,
+/// the rounding of a fully saturated light color such as `#ffeb3b`
+/// would otherwise give a saturation slightly above 1.0 which panics.
+fn to_hsla(color: RGBA) -> HSLA {
+    let RGBA { r, g, b, a } = color;
+    if r == g && g == b {
+        return HSLA {
+            h: deg(0),
+            s: percent(0),
+            l: r,
+            a,
+        };
+    }
+    let (r, g, b) = (r.as_f32(), g.as_f32(), b.as_f32());
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let luminosity = (max + min) / 2.0;
+    let saturation = if luminosity < 0.5 {
+        (max - min) / (max + min)
+    } else {
+        (max - min) / (2.0 - (max + min))
+    };
+    let hue = if max == r {
+        60.0 * (g - b) / (max - min)
+    } else if max == g {
+        120.0 + 60.0 * (b - r) / (max - min)
+    } else {
+        240.0 + 60.0 * (r - g) / (max - min)
+    };
+    HSLA {
+        h: deg(hue.round() as i32),
+        s: Ratio::from_f32(saturation.clamp(0.0, 1.0)),
+        l: Ratio::from_f32(luminosity),
+        a,
+    }
+}
+
 fn hex_to_real_rgba(hex: &str) -> Result<RGBA, ParseColorError> {
     let from_hex: css_color::Rgba = hex.parse()?;
     Ok(convert_to_real_rgba(from_hex))
 }
 
+/// parse a css color, which can be a 3, 4, 6 or 8 digit hex,
+/// `rgb()`, `rgba()`, `hsl()`, `hsla()` or a named color
+pub fn parse_color(color: &str) -> Result<RGBA, ThemeError> {
+    let color = color.trim();
+    if let Some(digits) = color.strip_prefix('#') {
+        let valid_len = matches!(digits.len(), 3 | 4 | 6 | 8);
+        let valid_digits = digits.chars().all(|c| c.is_ascii_hexdigit());
+        if !valid_len || !valid_digits {
+            return Err(ThemeError::MalformedHex(color.to_string()));
+        }
+    }
+    hex_to_real_rgba(color)
+        .map_err(|_| ThemeError::UnsupportedFormat(color.to_string()))
+}
+
+/// serialize the pallete colors as css color strings
+mod css_rgba {
+    use super::parse_color;
+    use css_colors::{Color, RGBA};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(color: &RGBA, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&color.to_css())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RGBA, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let css = String::deserialize(deserializer)?;
+        parse_color(&css).map_err(de::Error::custom)
+    }
+}
+
 trait IsLighter {
     fn is_lighter(&self, other: &Self) -> bool;
 }
@@ -269,11 +479,9 @@ where
 {
     fn is_lighter(&self, other: &Self) -> bool {
         let this = self.clone().to_rgb().greyscale();
-        dbg!(&this);
         assert_eq!(this.r, this.g);
         assert_eq!(this.g, this.b);
         let other = other.clone().to_rgb().greyscale();
-        dbg!(&other);
         assert_eq!(other.r, other.g);
         assert_eq!(other.g, other.b);
 
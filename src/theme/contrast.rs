@@ -0,0 +1,250 @@
+//! WCAG 2.x contrast checking of the text colors in a theme
+//! https://www.w3.org/TR/WCAG21/#contrast-minimum
+use super::{parse_color, Theme, ThemeError};
+use css_colors::{rgba, Color, RGBA};
+
+/// how much the text color is moved towards black or white in each nudge
+const NUDGE_STEP: f32 = 0.05;
+
+/// The WCAG conformance level for normal sized text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContrastLevel {
+    /// contrast ratio of at least 4.5:1
+    AA,
+    /// contrast ratio of at least 7:1
+    AAA,
+}
+
+impl ContrastLevel {
+    /// the minimum contrast ratio required by this level
+    pub fn min_ratio(&self) -> f32 {
+        match self {
+            ContrastLevel::AA => 4.5,
+            ContrastLevel::AAA => 7.0,
+        }
+    }
+}
+
+/// The contrast of one text color against the background it is displayed on
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastCheck {
+    /// the name of the theme field of the text color
+    pub name: &'static str,
+    pub foreground: String,
+    pub background: String,
+    pub ratio: f32,
+    /// the text color has been nudged to meet the required ratio
+    pub adjusted: bool,
+}
+
+/// The result of checking the text colors of a theme
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeReport {
+    pub level: ContrastLevel,
+    pub checks: Vec<ContrastCheck>,
+    /// the text colors which could not be checked, since the text or the background
+    /// is not a color that can be parsed, such as a `var(--fui-*)` reference
+    pub skipped: Vec<&'static str>,
+}
+
+impl ThemeReport {
+    /// the checks which did not meet the required ratio of the level
+    pub fn violations(&self) -> impl Iterator<Item = &ContrastCheck> {
+        let min_ratio = self.level.min_ratio();
+        self.checks
+            .iter()
+            .filter(move |check| check.ratio < min_ratio)
+    }
+
+    /// true if all of the text colors are checked and meet the required ratio
+    pub fn passes(&self) -> bool {
+        self.violations().next().is_none() && self.skipped.is_empty()
+    }
+}
+
+impl Theme {
+    /// check the contrast of the text colors of this theme against their backgrounds
+    pub fn check_contrast(&self, level: ContrastLevel) -> ThemeReport {
+        let mut checks = vec![];
+        let mut skipped = vec![];
+        for (name, foreground, background) in self.text_pairs() {
+            match (parse_color(&foreground), background) {
+                (Ok(fg), Ok(bg)) => checks.push(ContrastCheck {
+                    name,
+                    foreground,
+                    background: bg.to_css(),
+                    ratio: contrast_ratio(&composite(&fg, &bg), &bg),
+                    adjusted: false,
+                }),
+                (Err(e), _) | (_, Err(e)) => {
+                    log::warn!(
+                        "unable to check the contrast of {}: {}",
+                        name,
+                        e
+                    );
+                    skipped.push(name);
+                }
+            }
+        }
+        ThemeReport {
+            level,
+            checks,
+            skipped,
+        }
+    }
+
+    /// nudge the text colors of this theme towards black or white
+    /// until they meet the contrast ratio required by the level.
+    /// The colors which can not be parsed are left as they are and reported as skipped.
+    pub fn ensure_contrast(&mut self, level: ContrastLevel) -> ThemeReport {
+        let min_ratio = level.min_ratio();
+        let mut checks = vec![];
+        let mut skipped = vec![];
+        for (name, foreground, background) in self.text_pairs() {
+            let (original, background) =
+                match (parse_color(&foreground), background) {
+                    (Ok(original), Ok(background)) => (original, background),
+                    (Err(e), _) | (_, Err(e)) => {
+                        log::warn!(
+                            "unable to ensure the contrast of {}: {}",
+                            name,
+                            e
+                        );
+                        skipped.push(name);
+                        continue;
+                    }
+                };
+            let nudged = nudge(original, &background, min_ratio);
+            let adjusted = nudged != original;
+            if adjusted {
+                log::debug!("nudged {} from {} to {}", name, original, nudged);
+                *self.text_color_mut(name) = nudged.to_css();
+            }
+            checks.push(ContrastCheck {
+                name,
+                foreground: nudged.to_css(),
+                background: background.to_css(),
+                ratio: contrast_ratio(
+                    &composite(&nudged, &background),
+                    &background,
+                ),
+                adjusted,
+            });
+        }
+        ThemeReport {
+            level,
+            checks,
+            skipped,
+        }
+    }
+
+    /// the text colors and the opaque background color they are displayed on
+    fn text_pairs(
+        &self,
+    ) -> Vec<(&'static str, String, Result<RGBA, ThemeError>)> {
+        let page = parse_color(&self.background_color);
+        let content = page.clone().and_then(|page| {
+            parse_color(&self.controls.content_background_color)
+                .map(|content| composite(&content, &page))
+        });
+        vec![
+            (
+                "secondary_color",
+                self.secondary_color.clone(),
+                page.clone(),
+            ),
+            ("accent_color", self.accent_color.clone(), page.clone()),
+            (
+                "controls.link_color",
+                self.controls.link_color.clone(),
+                page,
+            ),
+            (
+                "controls.button_text_color",
+                self.controls.button_text_color.clone(),
+                content,
+            ),
+        ]
+    }
+
+    fn text_color_mut(&mut self, name: &str) -> &mut String {
+        match name {
+            "secondary_color" => &mut self.secondary_color,
+            "accent_color" => &mut self.accent_color,
+            "controls.link_color" => &mut self.controls.link_color,
+            "controls.button_text_color" => {
+                &mut self.controls.button_text_color
+            }
+            _ => unreachable!("not a text color: {}", name),
+        }
+    }
+}
+
+/// the relative luminance of the color, ignoring the alpha
+pub fn relative_luminance(color: &RGBA) -> f32 {
+    let channel = |c: f32| {
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(color.r.as_f32())
+        + 0.7152 * channel(color.g.as_f32())
+        + 0.0722 * channel(color.b.as_f32())
+}
+
+/// the contrast ratio of the 2 colors, ranging from 1.0 to 21.0
+pub fn contrast_ratio(a: &RGBA, b: &RGBA) -> f32 {
+    let la = relative_luminance(a);
+    let lb = relative_luminance(b);
+    let (lighter, darker) = if la > lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// the color as it appears when painted over an opaque background
+fn composite(color: &RGBA, background: &RGBA) -> RGBA {
+    let alpha = color.a.as_f32();
+    let blend = |fg: u8, bg: u8| {
+        (fg as f32 * alpha + bg as f32 * (1.0 - alpha)).round() as u8
+    };
+    rgba(
+        blend(color.r.as_u8(), background.r.as_u8()),
+        blend(color.g.as_u8(), background.g.as_u8()),
+        blend(color.b.as_u8(), background.b.as_u8()),
+        1.0,
+    )
+}
+
+/// move the color towards black on light backgrounds, or towards white on dark backgrounds
+/// until the contrast ratio is met or the color can not be moved any further.
+fn nudge(original: RGBA, background: &RGBA, min_ratio: f32) -> RGBA {
+    let color = composite(&original, background);
+    if contrast_ratio(&color, background) >= min_ratio {
+        return original;
+    }
+    let white = rgba(255, 255, 255, 1.0);
+    let black = rgba(0, 0, 0, 1.0);
+    let target = if contrast_ratio(&black, background)
+        > contrast_ratio(&white, background)
+    {
+        black
+    } else {
+        white
+    };
+    let mix = |from: u8, to: u8, amount: f32| {
+        (from as f32 + (to as f32 - from as f32) * amount).round() as u8
+    };
+    let mut amount = 0.0;
+    let mut nudged = color;
+    while amount < 1.0 && contrast_ratio(&nudged, background) < min_ratio {
+        amount = (amount + NUDGE_STEP).min(1.0);
+        nudged = rgba(
+            mix(color.r.as_u8(), target.r.as_u8(), amount),
+            mix(color.g.as_u8(), target.g.as_u8(), amount),
+            mix(color.b.as_u8(), target.b.as_u8(), amount),
+            1.0,
+        );
+    }
+    nudged
+}
@@ -0,0 +1,55 @@
+use super::ThemeReport;
+use std::fmt;
+
+/// The errors encountered when building a theme from user supplied colors
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeError {
+    /// the color starts with `#` but is not a 3, 4, 6 or 8 digit hex color
+    MalformedHex(String),
+    /// the color is not a hex, `rgb()`, `rgba()`, `hsl()`, `hsla()` or a named css color
+    UnsupportedFormat(String),
+    /// a required color is missing, such as the primary or background in the url hash
+    MissingSegment(&'static str),
+    /// the text colors of the theme did not meet the required contrast ratio
+    LowContrast(ThemeReport),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ThemeError::MalformedHex(color) => write!(
+                f,
+                "malformed hex color {:?}, expecting 3, 4, 6 or 8 hex digits",
+                color
+            ),
+            ThemeError::UnsupportedFormat(color) => write!(
+                f,
+                "unsupported color {:?}, use a hex, rgb(), hsl() or a named css color",
+                color
+            ),
+            ThemeError::MissingSegment(segment) => {
+                write!(f, "missing {} color", segment)
+            }
+            ThemeError::LowContrast(report) => {
+                let names: Vec<String> = report
+                    .violations()
+                    .map(|check| format!("{} ({:.2}:1)", check.name, check.ratio))
+                    .chain(
+                        report
+                            .skipped
+                            .iter()
+                            .map(|name| format!("{} (not a color)", name)),
+                    )
+                    .collect();
+                write!(
+                    f,
+                    "text colors does not meet the {:.1}:1 contrast ratio: {}",
+                    report.level.min_ratio(),
+                    names.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
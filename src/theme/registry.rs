@@ -0,0 +1,79 @@
+use super::Theme;
+use std::collections::BTreeMap;
+
+/// name of the theme that is active when the registry is created
+pub const DEFAULT_THEME: &str = "bondi_blue_on_dark";
+
+/// A collection of named themes, with one of them being the active theme.
+/// The registry starts with the built-in presets, and custom themes can be
+/// registered alongside them.
+#[derive(Debug, Clone)]
+pub struct ThemeRegistry {
+    themes: BTreeMap<String, Theme>,
+    current: String,
+}
+
+impl ThemeRegistry {
+    /// create a registry with the built-in presets
+    pub fn new() -> Self {
+        let mut themes = BTreeMap::new();
+        themes.insert(DEFAULT_THEME.to_string(), Theme::bondi_blue_on_dark());
+        themes.insert("white_on_dark".to_string(), Theme::white_on_dark());
+        themes.insert("green_on_black".to_string(), Theme::green_on_black());
+        themes.insert("black_on_white".to_string(), Theme::black_on_white());
+        ThemeRegistry {
+            themes,
+            current: DEFAULT_THEME.to_string(),
+        }
+    }
+
+    /// register a theme under this name, replacing and returning the
+    /// previous theme with the same name.
+    pub fn register(
+        &mut self,
+        name: impl ToString,
+        theme: Theme,
+    ) -> Option<Theme> {
+        self.themes.insert(name.to_string(), theme)
+    }
+
+    /// the theme registered under this name
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.get(name)
+    }
+
+    /// the names of all the registered themes, in alphabetical order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.themes.keys().map(|name| name.as_str())
+    }
+
+    /// make the theme with this name the active theme.
+    /// Returns None and leave the active theme unchanged if there is no theme
+    /// registered with this name.
+    pub fn set_theme(&mut self, name: &str) -> Option<&Theme> {
+        if self.themes.contains_key(name) {
+            self.current = name.to_string();
+            self.themes.get(name)
+        } else {
+            None
+        }
+    }
+
+    /// the name of the active theme
+    pub fn current_name(&self) -> &str {
+        &self.current
+    }
+
+    /// the active theme
+    pub fn current(&self) -> &Theme {
+        self.themes
+            .get(&self.current)
+            .expect("current theme must be registered")
+    }
+}
+
+impl Default for ThemeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,88 @@
+use super::{parse_color, ContrastLevel, Pallete, Theme, ThemeError};
+use css_colors::{rgba, RGBA};
+use std::str::FromStr;
+
+/// A pair of light and dark themes derived from the same primary color,
+/// the theme is selected by the `prefers-color-scheme` of the user's system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorScheme {
+    /// the theme with a light background
+    pub light: Theme,
+    /// the theme with a dark background, which is also used
+    /// when the system has no preference
+    pub dark: Theme,
+}
+
+impl ColorScheme {
+    /// derive the light and dark themes from this primary color,
+    /// the text colors of both themes are nudged to meet the AA contrast ratio.
+    pub fn from_primary(primary: RGBA, pallete: Pallete) -> Self {
+        Self::from_primary_with_contrast(primary, pallete, ContrastLevel::AA)
+    }
+
+    /// derive the light and dark themes from this primary color,
+    /// with the text colors meeting the contrast ratio of the level.
+    pub fn from_primary_with_contrast(
+        primary: RGBA,
+        pallete: Pallete,
+        level: ContrastLevel,
+    ) -> Self {
+        let white = rgba(255, 255, 255, 1.0);
+        let black = rgba(0, 0, 0, 1.0);
+        let (light, _) = Theme::calculate_theme_with_contrast(
+            primary,
+            white,
+            pallete.clone(),
+            level,
+        );
+        let (dark, _) = Theme::calculate_theme_with_contrast(
+            primary, black, pallete, level,
+        );
+        ColorScheme { light, dark }
+    }
+
+    /// the theme to be used when the system prefers a dark or light scheme
+    pub fn theme(&self, prefers_dark: bool) -> &Theme {
+        if prefers_dark {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+
+    /// the css variables of the dark theme, overriden by the light theme
+    /// when the system prefers a light color scheme.
+    /// Use this with stylesheets generated from `Theme::with_css_variables`.
+    pub fn css_variables(&self) -> String {
+        let light = self
+            .light
+            .css_variables()
+            .lines()
+            .map(|line| format!("    {}", line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "{}@media (prefers-color-scheme: light) {{\n{}\n}}\n",
+            self.dark.css_variables(),
+            light
+        )
+    }
+}
+
+/// derive the light and dark themes from a primary color that can be parse
+impl FromStr for ColorScheme {
+    type Err = ThemeError;
+
+    fn from_str(primary: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_primary(
+            parse_color(primary)?,
+            Pallete::default(),
+        ))
+    }
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self::from_primary(rgba(2, 157, 187, 1.0), Pallete::default())
+    }
+}
@@ -178,3 +178,442 @@ fn test_color_processing() {
     println!("darken: {}", darken.to_rgb_string());
     assert_eq!(darken.to_rgb_string(), "rgb(0, 208, 243)");
 }
+
+#[test]
+fn theme_json_round_trip() {
+    let theme = Theme::from_str("#029dbb", "black").unwrap();
+    let json = theme.to_json();
+    let loaded = Theme::from_json(&json).unwrap();
+    assert_eq!(loaded, theme);
+}
+
+#[test]
+fn theme_json_overrides_computed_fields() {
+    let json = r##"{
+        "controls": {
+            "hover_shadow": "rgba(255, 0, 0, 1.00)",
+            "link_color": "#ff00ff"
+        },
+        "pallete": {
+            "error": "#ff8800"
+        }
+    }"##;
+    let theme = Theme::from_json(json).unwrap();
+    let default = Theme::default();
+    assert_eq!(theme.controls.hover_shadow, "rgba(255, 0, 0, 1.00)");
+    assert_eq!(theme.controls.link_color, "#ff00ff");
+    assert_eq!(theme.controls.border_color, default.controls.border_color);
+    assert_eq!(theme.pallete.error, rgba(255, 136, 0, 1.0));
+    assert_eq!(theme.pallete.info, Pallete::default().info);
+    assert_eq!(theme.primary_color, default.primary_color);
+}
+
+#[test]
+fn theme_json_rejects_invalid_pallete_color() {
+    let json = r#"{ "pallete": { "error": "not-a-color" } }"#;
+    assert!(Theme::from_json(json).is_err());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn theme_toml_round_trip() {
+    let theme = Theme::from_str("green", "white").unwrap();
+    let toml = theme.to_toml();
+    let loaded = Theme::from_toml(&toml).unwrap();
+    assert_eq!(loaded, theme);
+}
+
+#[test]
+fn registry_has_presets() {
+    let registry = ThemeRegistry::new();
+    let names: Vec<&str> = registry.names().collect();
+    assert_eq!(
+        names,
+        [
+            "black_on_white",
+            "bondi_blue_on_dark",
+            "green_on_black",
+            "white_on_dark"
+        ]
+    );
+    assert_eq!(registry.current_name(), "bondi_blue_on_dark");
+    assert_eq!(registry.current(), &Theme::default());
+}
+
+#[test]
+fn registry_switch_theme() {
+    let mut registry = ThemeRegistry::new();
+    assert_eq!(
+        registry.set_theme("black_on_white"),
+        Some(&Theme::black_on_white())
+    );
+    assert_eq!(registry.current(), &Theme::black_on_white());
+
+    assert_eq!(registry.set_theme("no_such_theme"), None);
+    assert_eq!(registry.current_name(), "black_on_white");
+}
+
+#[test]
+fn registry_custom_theme() {
+    let mut registry = ThemeRegistry::new();
+    let purple = Theme::from_str("purple", "black").unwrap();
+    assert_eq!(registry.register("purple", purple.clone()), None);
+    assert_eq!(registry.set_theme("purple"), Some(&purple));
+
+    let replaced = Theme::from_str("purple", "white").unwrap();
+    assert_eq!(registry.register("purple", replaced.clone()), Some(purple));
+    assert_eq!(registry.current(), &replaced);
+}
+
+#[test]
+fn contrast_ratio_of_black_and_white() {
+    let white = rgba(255, 255, 255, 1.0);
+    let black = rgba(0, 0, 0, 1.0);
+    assert!((contrast_ratio(&white, &black) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(&black, &white) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(&white, &white) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn contrast_ratio_of_known_pair() {
+    // #767676 on white is the commonly cited lightest grey that passes AA
+    let grey = rgba(118, 118, 118, 1.0);
+    let white = rgba(255, 255, 255, 1.0);
+    let ratio = contrast_ratio(&grey, &white);
+    assert!((4.5..4.6).contains(&ratio), "ratio: {}", ratio);
+}
+
+#[test]
+fn default_theme_passes_aa() {
+    let report = Theme::default().check_contrast(ContrastLevel::AA);
+    assert_eq!(report.checks.len(), 4);
+    assert!(report.passes(), "{:#?}", report);
+}
+
+#[test]
+fn yellow_on_white_is_reported() {
+    let theme = Theme::from_str("yellow", "white").unwrap();
+    let report = theme.check_contrast(ContrastLevel::AA);
+    assert!(!report.passes());
+    let violations: Vec<&str> =
+        report.violations().map(|check| check.name).collect();
+    assert!(violations.contains(&"secondary_color"), "{:?}", violations);
+}
+
+#[test]
+fn yellow_on_white_is_nudged_to_aa() {
+    let (theme, report) = Theme::calculate_theme_with_contrast(
+        rgba(255, 255, 0, 1.0),
+        rgba(255, 255, 255, 1.0),
+        Pallete::default(),
+        ContrastLevel::AA,
+    );
+    assert!(report.passes(), "{:#?}", report);
+    assert!(report.checks.iter().any(|check| check.adjusted));
+    assert!(theme.check_contrast(ContrastLevel::AA).passes());
+    // non-text colors are left untouched
+    let original = Theme::from_str("yellow", "white").unwrap();
+    assert_eq!(theme.primary_color, original.primary_color);
+    assert_eq!(theme.controls.border_color, original.controls.border_color);
+}
+
+#[test]
+fn ensure_contrast_aaa() {
+    let mut theme = Theme::from_str("purple", "black").unwrap();
+    let report = theme.ensure_contrast(ContrastLevel::AAA);
+    assert!(report.passes(), "{:#?}", report);
+    for check in report.checks.iter() {
+        assert!(check.ratio >= 7.0, "{:?}", check);
+    }
+}
+
+#[test]
+fn passing_colors_are_not_adjusted() {
+    let mut theme = Theme::default();
+    let report = theme.ensure_contrast(ContrastLevel::AA);
+    assert!(report.checks.iter().all(|check| !check.adjusted));
+    assert_eq!(theme, Theme::default());
+}
+
+#[test]
+fn css_variables_are_skipped_not_checked() {
+    let variables = Theme::default().with_css_variables();
+    let report = variables.check_contrast(ContrastLevel::AA);
+    assert!(report.checks.is_empty(), "{:#?}", report);
+    assert_eq!(report.skipped.len(), 4);
+    assert!(!report.passes());
+
+    // the references to the variables are kept as they are
+    let mut ensured = variables.clone();
+    let report = ensured.ensure_contrast(ContrastLevel::AA);
+    assert_eq!(report.skipped.len(), 4);
+    assert_eq!(ensured, variables);
+}
+
+#[test]
+fn pallete_from_str() {
+    let pallete = Pallete::from_str(Some("#f08"), None, Some("cyan"), None)
+        .expect("must parse");
+    assert_eq!(pallete.error, rgba(255, 0, 136, 1.0));
+    assert_eq!(pallete.success, Pallete::default().success);
+    assert_eq!(pallete.info, rgba(0, 255, 255, 1.0));
+    assert_eq!(pallete.warning, Pallete::default().warning);
+
+    assert!(Pallete::from_str(Some("nope"), None, None, None).is_err());
+}
+
+#[test]
+fn theme_from_str_with_pallete() {
+    let pallete = Pallete::from_str(Some("orange"), None, None, None).unwrap();
+    let theme =
+        Theme::from_str_with_pallete("#029dbb", "black", pallete.clone())
+            .unwrap();
+    let expected = Theme::calculate_theme(
+        rgba(2, 157, 187, 1.0),
+        rgba(0, 0, 0, 1.0),
+        pallete,
+    );
+    assert_eq!(theme, expected);
+    assert_ne!(theme.pallete, Theme::default().pallete);
+}
+
+#[test]
+fn theme_from_url_hash() {
+    assert_eq!(
+        Theme::from_url_hash("#/%23029dbb/black").unwrap(),
+        Theme::default()
+    );
+    assert_eq!(
+        Theme::from_url_hash("#/#029dbb/black").unwrap(),
+        Theme::default()
+    );
+
+    let theme = Theme::from_url_hash("#/green/white/purple//%23123/").unwrap();
+    let pallete =
+        Pallete::from_str(Some("purple"), None, Some("#123"), None).unwrap();
+    assert_eq!(
+        theme,
+        Theme::from_str_with_pallete("green", "white", pallete).unwrap()
+    );
+}
+
+#[test]
+fn theme_from_invalid_url_hash() {
+    assert!(Theme::from_url_hash("#/green").is_err());
+    assert!(Theme::from_url_hash("#/green/notacolor").is_err());
+    assert!(Theme::from_url_hash("#/green/black/notacolor").is_err());
+}
+
+#[test]
+fn parse_color_formats() {
+    let bondi_blue = rgba(2, 157, 187, 1.0);
+    assert_eq!(parse_color("#029dbb").unwrap(), bondi_blue);
+    assert_eq!(parse_color("#029dbbff").unwrap(), bondi_blue);
+    assert_eq!(parse_color("#0f0").unwrap(), rgba(0, 255, 0, 1.0));
+    assert_eq!(
+        parse_color("#0f08").unwrap(),
+        rgba(0, 255, 0, 136.0 / 255.0)
+    );
+    assert_eq!(parse_color("rgb(2, 157, 187)").unwrap(), bondi_blue);
+    assert_eq!(
+        parse_color("rgba(2, 157, 187, 0.5)").unwrap(),
+        rgba(2, 157, 187, 0.5)
+    );
+    assert_eq!(
+        parse_color("hsl(120, 100%, 50%)").unwrap(),
+        rgba(0, 255, 0, 1.0)
+    );
+    assert_eq!(parse_color("purple").unwrap(), rgba(128, 0, 128, 1.0));
+}
+
+#[test]
+fn parse_color_errors() {
+    assert_eq!(
+        parse_color("#12345"),
+        Err(ThemeError::MalformedHex("#12345".to_string()))
+    );
+    assert_eq!(
+        parse_color("#ggg"),
+        Err(ThemeError::MalformedHex("#ggg".to_string()))
+    );
+    assert_eq!(
+        parse_color("notacolor"),
+        Err(ThemeError::UnsupportedFormat("notacolor".to_string()))
+    );
+    assert_eq!(
+        parse_color("cmyk(0, 0, 0, 0)"),
+        Err(ThemeError::UnsupportedFormat(
+            "cmyk(0, 0, 0, 0)".to_string()
+        ))
+    );
+}
+
+#[test]
+fn url_hash_missing_segments() {
+    assert_eq!(
+        Theme::from_url_hash("#/"),
+        Err(ThemeError::MissingSegment("primary"))
+    );
+    assert_eq!(
+        Theme::from_url_hash("#/green"),
+        Err(ThemeError::MissingSegment("background"))
+    );
+    assert_eq!(
+        Theme::from_url_hash("#/green/#12"),
+        Err(ThemeError::MalformedHex("#12".to_string()))
+    );
+}
+
+#[test]
+fn low_contrast_theme_is_rejected() {
+    let theme = Theme::from_str("yellow", "white").unwrap();
+    match theme.validate_contrast(ContrastLevel::AA) {
+        Err(ThemeError::LowContrast(report)) => {
+            assert!(!report.passes());
+            let message = ThemeError::LowContrast(report).to_string();
+            assert!(message.contains("secondary_color"), "{}", message);
+        }
+        other => panic!("expecting low contrast error, got: {:?}", other),
+    }
+    assert!(Theme::default()
+        .validate_contrast(ContrastLevel::AA)
+        .is_ok());
+}
+
+#[test]
+fn theme_error_messages() {
+    assert_eq!(
+        ThemeError::MissingSegment("background").to_string(),
+        "missing background color"
+    );
+    assert_eq!(
+        ThemeError::MalformedHex("#12".to_string()).to_string(),
+        "malformed hex color \"#12\", expecting 3, 4, 6 or 8 hex digits"
+    );
+}
+
+#[test]
+fn css_variables_block() {
+    let css = Theme::default().css_variables();
+    assert!(css.starts_with(":root {\n"), "{}", css);
+    assert!(css.contains("    --fui-primary-color: rgba(2, 157, 187, 1.00);\n"));
+    assert!(css.contains("    --fui-pallete-error: "));
+    assert!(css.contains("    --fui-controls-link-color: "));
+    assert!(
+        css.contains("    --fui-bullet-icon: url('data:image/svg+xml;base64,")
+    );
+}
+
+#[test]
+fn theme_with_css_variables() {
+    let theme = Theme::default().with_css_variables();
+    assert!(theme.uses_css_variables());
+    assert!(!Theme::default().uses_css_variables());
+    assert_eq!(theme.primary_color, "var(--fui-primary-color)");
+    assert_eq!(
+        theme.controls.button_text_color,
+        "var(--fui-controls-button-text-color)"
+    );
+    assert_eq!(theme.error_color(), "var(--fui-pallete-error)");
+    assert_eq!(
+        Theme::default().error_color(),
+        Theme::default().pallete.error.to_css()
+    );
+}
+
+#[test]
+fn css_variables_mode_survives_a_json_round_trip() {
+    let theme = Theme::default().with_css_variables();
+    let loaded = Theme::from_json(&theme.to_json()).unwrap();
+    assert!(loaded.uses_css_variables());
+    assert_eq!(loaded, theme);
+    assert_eq!(loaded.error_color(), "var(--fui-pallete-error)");
+}
+
+#[test]
+fn theme_can_be_built_from_its_fields() {
+    let defaults = Theme::default();
+    let theme = Theme {
+        primary_color: "#ff00ff".to_string(),
+        secondary_color: defaults.secondary_color.clone(),
+        background_color: defaults.background_color.clone(),
+        accent_color: defaults.accent_color.clone(),
+        accent_shadow: defaults.accent_shadow.clone(),
+        primary_font: defaults.primary_font.clone(),
+        secondary_font: defaults.secondary_font.clone(),
+        pallete: defaults.pallete.clone(),
+        controls: defaults.controls.clone(),
+    };
+    assert!(!theme.uses_css_variables());
+    assert_ne!(theme, defaults);
+}
+
+#[test]
+fn every_variable_is_declared() {
+    let declared = Theme::default().css_variables();
+    let theme = Theme::default().with_css_variables();
+    let styles = [
+        crate::common::style(&theme),
+        crate::Button::<()>::style(&theme),
+        crate::Frame::<()>::style(&theme),
+        crate::NavHeader::<()>::style(&theme),
+        crate::Spinner::<()>::style(&theme),
+        crate::AnimateList::<()>::style(&theme),
+    ];
+    for style in styles.iter() {
+        assert!(!style.contains("rgba("), "{}", style);
+        for reference in style.split("var(").skip(1) {
+            let name = reference.split(')').next().unwrap();
+            assert!(
+                declared.contains(&format!("{}: ", name)),
+                "{} is not declared",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn color_scheme_from_primary() {
+    let scheme = "#029dbb".parse::<ColorScheme>().unwrap();
+    assert_eq!(scheme.dark, Theme::default());
+    assert_eq!(scheme.light, Theme::from_str("#029dbb", "white").unwrap());
+    assert_eq!(scheme.theme(true), &scheme.dark);
+    assert_eq!(scheme.theme(false), &scheme.light);
+    assert_eq!(scheme, ColorScheme::default());
+    assert!("#12".parse::<ColorScheme>().is_err());
+}
+
+#[test]
+fn color_scheme_from_the_primary_of_a_theme() {
+    let theme = Theme::from_str("#ffeb3b", "black").unwrap();
+    let scheme = theme.primary_color.parse::<ColorScheme>().unwrap();
+    assert_eq!(scheme, "#ffeb3b".parse::<ColorScheme>().unwrap());
+}
+
+#[test]
+fn color_scheme_of_extreme_primaries_is_readable() {
+    for primary in ["#fff", "#000", "#ffeb3b"] {
+        let scheme = primary.parse::<ColorScheme>().unwrap();
+        for theme in [&scheme.light, &scheme.dark] {
+            let report = theme.check_contrast(ContrastLevel::AA);
+            assert!(report.passes(), "{}: {:#?}", primary, report);
+        }
+    }
+}
+
+#[test]
+fn color_scheme_css_variables() {
+    let scheme = "green".parse::<ColorScheme>().unwrap();
+    let css = scheme.css_variables();
+    let (dark, light) = css
+        .split_once("@media (prefers-color-scheme: light) {\n")
+        .expect("must have a light media query");
+    assert_eq!(dark, scheme.dark.css_variables());
+    assert!(light.starts_with("    :root {\n"), "{}", light);
+    assert!(light.contains(&format!(
+        "        --fui-background-color: {};",
+        scheme.light.background_color
+    )));
+    assert!(light.ends_with("    }\n}\n"), "{}", light);
+}
@@ -0,0 +1,131 @@
+//! Css custom properties output mode.
+//!
+//! The stylesheets of the widgets are generated once from `Theme::with_css_variables`
+//! which only contains `var(--fui-*)` references. Switching theme is then
+//! just a matter of replacing the small `:root` block from `Theme::css_variables`.
+//!
+//! The mode is not stored in the theme, it is recognized from the colors themselves
+//! so a theme that is compared, cloned or saved as json stays in the same mode.
+use super::Theme;
+use css_colors::Color;
+
+/// prefix of the css custom properties of the theme
+pub const VARIABLE_PREFIX: &str = "--fui-";
+
+impl Theme {
+    /// a theme where every color and font refers to a css custom property,
+    /// use this to generate the stylesheets of the widgets once.
+    pub fn with_css_variables(&self) -> Self {
+        let mut theme = self.clone();
+        theme.primary_color = var("primary-color");
+        theme.secondary_color = var("secondary-color");
+        theme.background_color = var("background-color");
+        theme.accent_color = var("accent-color");
+        theme.accent_shadow = var("accent-shadow");
+        theme.primary_font = var("primary-font");
+        theme.secondary_font = var("secondary-font");
+
+        let controls = &mut theme.controls;
+        controls.hover_color = var("controls-hover-color");
+        controls.hover_shadow = var("controls-hover-shadow");
+        controls.border_color = var("controls-border-color");
+        controls.corner_color = var("controls-corner-color");
+        controls.border_shadow = var("controls-border-shadow");
+        controls.corner_shadow = var("controls-corner-shadow");
+        controls.content_background_color =
+            var("controls-content-background-color");
+        controls.button_text_color = var("controls-button-text-color");
+        controls.highlight_color = var("controls-highlight-color");
+        controls.link_color = var("controls-link-color");
+
+        theme
+    }
+
+    /// true if this theme refers to css custom properties instead of actual colors,
+    /// which is when the primary color is the reference set by `with_css_variables`
+    pub fn uses_css_variables(&self) -> bool {
+        self.primary_color == var("primary-color")
+    }
+
+    /// the `:root` block which declares the css custom properties of this theme
+    pub fn css_variables(&self) -> String {
+        let declarations: Vec<String> = self
+            .variables()
+            .into_iter()
+            .map(|(name, value)| {
+                format!("    {}{}: {};", VARIABLE_PREFIX, name, value)
+            })
+            .collect();
+        format!(":root {{\n{}\n}}\n", declarations.join("\n"))
+    }
+
+    /// the css custom property names and their values
+    fn variables(&self) -> Vec<(&'static str, String)> {
+        let controls = &self.controls;
+        vec![
+            ("primary-color", self.primary_color.clone()),
+            ("secondary-color", self.secondary_color.clone()),
+            ("background-color", self.background_color.clone()),
+            ("accent-color", self.accent_color.clone()),
+            ("accent-shadow", self.accent_shadow.clone()),
+            ("primary-font", self.primary_font.clone()),
+            ("secondary-font", self.secondary_font.clone()),
+            ("pallete-error", self.pallete.error.to_css()),
+            ("pallete-success", self.pallete.success.to_css()),
+            ("pallete-info", self.pallete.info.to_css()),
+            ("pallete-warning", self.pallete.warning.to_css()),
+            ("controls-hover-color", controls.hover_color.clone()),
+            ("controls-hover-shadow", controls.hover_shadow.clone()),
+            ("controls-border-color", controls.border_color.clone()),
+            ("controls-corner-color", controls.corner_color.clone()),
+            ("controls-border-shadow", controls.border_shadow.clone()),
+            ("controls-corner-shadow", controls.corner_shadow.clone()),
+            (
+                "controls-content-background-color",
+                controls.content_background_color.clone(),
+            ),
+            (
+                "controls-button-text-color",
+                controls.button_text_color.clone(),
+            ),
+            ("controls-highlight-color", controls.highlight_color.clone()),
+            ("controls-link-color", controls.link_color.clone()),
+            ("bullet-icon", crate::common::bullet_icon_url(self)),
+        ]
+    }
+
+    /// the css color of the error pallete
+    pub fn error_color(&self) -> String {
+        self.pallete_color("pallete-error", self.pallete.error.to_css())
+    }
+
+    /// the css color of the success pallete
+    pub fn success_color(&self) -> String {
+        self.pallete_color("pallete-success", self.pallete.success.to_css())
+    }
+
+    /// the css color of the info pallete
+    pub fn info_color(&self) -> String {
+        self.pallete_color("pallete-info", self.pallete.info.to_css())
+    }
+
+    /// the css color of the warning pallete
+    pub fn warning_color(&self) -> String {
+        self.pallete_color("pallete-warning", self.pallete.warning.to_css())
+    }
+
+    /// the pallete colors are not strings, so they are referred to
+    /// by their variable name when this theme uses css variables.
+    fn pallete_color(&self, name: &str, color: String) -> String {
+        if self.uses_css_variables() {
+            var(name)
+        } else {
+            color
+        }
+    }
+}
+
+/// the reference to the css custom property of the theme
+fn var(name: &str) -> String {
+    format!("var({}{})", VARIABLE_PREFIX, name)
+}